@@ -0,0 +1,256 @@
+use std::env;
+use std::io::{self, ErrorKind, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+use adventofcode2025::aocutils::{Answers, BenchConfig, Format, InputSource, ParseError, Part, RunTimer, Runner, Settings, Verdict};
+
+#[path = "../day1/mod.rs"]
+mod day1;
+#[path = "../day2/mod.rs"]
+mod day2;
+#[path = "../day3/mod.rs"]
+mod day3;
+#[path = "../day4/mod.rs"]
+mod day4;
+#[path = "../day5/mod.rs"]
+mod day5;
+#[path = "../day6/mod.rs"]
+mod day6;
+#[path = "../day7/mod.rs"]
+mod day7;
+#[path = "../day8/mod.rs"]
+mod day8;
+#[path = "../day9/mod.rs"]
+mod day9;
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <text|json>] [--timings] [<day settings>]
+       aoc bench <day|all> [--part <1|2>] [--input <path|->] [--iterations <n> | --time <secs>] [--warmup <n>] [<day settings>]
+       aoc verify [day|all] [--part <1|2>] [--input <path|->]
+       aoc stream 5 [--input <path|->] [--list <fresh|spoiled>]
+
+The input defaults to $AOC_INPUT_DIR/dayN/input.txt when set, otherwise
+to the dayN/input.txt bundled with this repository. --timings prints a
+table of every timed phase to stderr once all days have run. bench runs
+for one second per phase unless given --iterations or --time. verify
+compares answers against the answers.txt next to each input and exits
+with a failure status if any differ. stream classifies day 5 IDs one line
+at a time without reading the whole input, printing the fresh or spoiled
+IDs with --list and the counts to stderr.
+
+Day settings change the puzzle, and with all each day only sees its own.
+--digits <k> sets how many digits day 3 part 2 picks from each line, 12
+by default. Day 4 counts a cell holding --symbol <c> (@) as accessible
+when the number of its --neighborhood <vn|moore|rN> (moore) neighbors
+also holding it passes --comparison <lt|le|eq|ge|gt> (lt) against
+--threshold <n> (4), and --toroidal wraps neighbors around the edges.";
+
+fn runner() -> Runner {
+    let mut runner = Runner::new();
+    runner
+        .register::<day1::Day1>()
+        .register::<day2::Day2>()
+        .register::<day3::Day3>()
+        .register::<day4::Day4>()
+        .register::<day5::Day5>()
+        .register::<day6::Day6>()
+        .register::<day7::Day7>()
+        .register::<day8::Day8>()
+        .register::<day9::Day9>();
+    runner
+}
+
+fn usage_error(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidInput, format!("{}\n{}", message, USAGE))
+}
+
+fn value<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> io::Result<T> {
+    let value = args.next().ok_or_else(|| usage_error(&format!("{} requires a value", flag)))?;
+    value.parse().map_err(|_| usage_error(&format!("invalid value for {}: {}", flag, value)))
+}
+
+#[derive(Default)]
+struct Options {
+    part: Option<Part>,
+    input: Option<String>,
+    format: Format,
+    timings: bool,
+    bench: BenchConfig,
+    list: Option<day5::Listing>,
+    settings: Settings,
+}
+
+fn parse_options(runner: &Runner, mut args: impl Iterator<Item = String>) -> io::Result<Options> {
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => options.part = Some(value(&mut args, &arg)?),
+            "--input" => options.input = Some(value(&mut args, &arg)?),
+            "--format" => options.format = value(&mut args, &arg)?,
+            "--timings" => options.timings = true,
+            "--iterations" => match value(&mut args, &arg)? {
+                0 => return Err(usage_error("--iterations must be at least 1")),
+                iterations => options.bench.iterations = Some(iterations),
+            },
+            "--time" => options.bench.budget = Duration::try_from_secs_f64(value(&mut args, &arg)?)
+                .map_err(|_| usage_error("--time must be a finite, non-negative number of seconds"))?,
+            "--warmup" => options.bench.warmup = value(&mut args, &arg)?,
+            "--list" => options.list = Some(value(&mut args, &arg)?),
+            other => match other.strip_prefix("--") {
+                Some(name) if runner.has_flag(name) => options.settings.set(name, "true"),
+                Some(name) if runner.has_setting(name) => options.settings.set(name, value::<String>(&mut args, &arg)?),
+                _ => return Err(usage_error(&format!("unknown argument: {}", other))),
+            },
+        }
+    }
+    Ok(options)
+}
+
+/// Day settings change the answers, so only run and bench take them.
+fn reject_settings(command: &str, options: &Options) -> io::Result<()> {
+    match options.settings.names().next() {
+        Some(name) => Err(usage_error(&format!("{} does not take --{}", command, name))),
+        None => Ok(()),
+    }
+}
+
+/// Each day only sees the settings it declares, but every setting given must
+/// apply to at least one of the days being run.
+fn check_settings(runner: &Runner, days: &[u8], options: &Options) -> io::Result<()> {
+    let unused = options.settings.names()
+        .find(|name| !days.iter().any(|&day| runner.accepts(day, name)));
+    match (unused, days) {
+        (Some(name), &[day]) => Err(usage_error(&format!("--{} does not apply to day {}", name, day))),
+        (Some(name), _) => Err(usage_error(&format!("--{} does not apply to any of the selected days", name))),
+        (None, _) => Ok(()),
+    }
+}
+
+fn run_days(runner: &Runner, days: &[u8], options: &Options) -> io::Result<ExitCode> {
+    let timer = RunTimer::new();
+    let mut out = io::stdout().lock();
+    for &day in days {
+        let source = InputSource::resolve(day, options.input.as_deref());
+        let report = runner.run(day, options.part, &source, &options.settings, &timer)?;
+        options.format.write(&mut out, &report)?;
+    }
+    out.flush()?;
+
+    if options.timings {
+        eprint!("{}", timer.summary());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn bench_days(runner: &Runner, days: &[u8], options: &Options) -> io::Result<ExitCode> {
+    let timer = RunTimer::new();
+    for &day in days {
+        let source = InputSource::resolve(day, options.input.as_deref());
+        runner.bench(day, options.part, &source, &options.settings, &options.bench, &timer)?;
+    }
+
+    print!("{}", timer.summary());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify_days(runner: &Runner, days: &[u8], options: &Options) -> io::Result<ExitCode> {
+    let timer = RunTimer::new();
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for &day in days {
+        let source = InputSource::resolve(day, options.input.as_deref());
+        let answers = Answers::load_for(&source)?;
+
+        let report = match runner.run(day, options.part, &source, &options.settings, &timer) {
+            Ok(report) => report,
+            Err(e) => {
+                println!("Day {}: FAIL ({})", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for answer in &report.answers {
+            let verdict = answers.check(&source, answer.part, &answer.answer);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+            println!("Day {} Part {}: {} {}", day, answer.part, answer.answer, verdict);
+        }
+    }
+
+    println!("\n{} passed, {} failed, {} unknown", passed, failed, unknown);
+    Ok(if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn stream_day(day: u8, options: &Options) -> io::Result<ExitCode> {
+    if day != 5 {
+        return Err(usage_error(&format!("day {} has no streaming mode", day)));
+    }
+
+    let source = InputSource::resolve(day, options.input.as_deref());
+    let mut out = io::BufWriter::new(io::stdout().lock());
+    let counts = day5::stream(source.open()?, options.list, &mut out)
+        .map_err(|e| ParseError::attach_file(e, source.to_string()))?;
+    out.flush()?;
+
+    let summary = format!("fresh: {}\nspoiled: {}", counts.fresh, counts.spoiled);
+    match options.list {
+        Some(_) => eprintln!("{}", summary),
+        None => println!("{}", summary),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run() -> io::Result<ExitCode> {
+    let runner = runner();
+    let mut args = env::args().skip(1).peekable();
+
+    let command = match args.next() {
+        Some(command) if ["run", "bench", "verify", "stream"].contains(&command.as_str()) => command,
+        Some(other) => return Err(usage_error(&format!("unknown command: {}", other))),
+        None => return Err(usage_error("missing command")),
+    };
+
+    // verify checks every day unless told otherwise
+    let verify_all = command == "verify" && args.peek().is_none_or(|arg| arg.starts_with("--"));
+    let days: Vec<u8> = match args.next_if(|_| !verify_all).as_deref() {
+        _ if verify_all => runner.days().collect(),
+        Some("all") => runner.days().collect(),
+        Some(day) => vec![day.parse().map_err(|_| usage_error(&format!("invalid day: {}", day)))?],
+        None => return Err(usage_error("missing day")),
+    };
+
+    let options = parse_options(&runner, args)?;
+    if options.input.is_some() && days.len() > 1 {
+        return Err(usage_error("--input can only be used with a single day"));
+    }
+    if command == "verify" || command == "stream" {
+        reject_settings(&command, &options)?;
+    } else {
+        check_settings(&runner, &days, &options)?;
+    }
+
+    match command.as_str() {
+        "bench" => bench_days(&runner, &days, &options),
+        "stream" => match days.as_slice() {
+            &[day] => stream_day(day, &options),
+            _ => Err(usage_error("stream takes a single day")),
+        },
+        "verify" => verify_days(&runner, &days, &options),
+        _ => run_days(&runner, &days, &options),
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::Display;
+use std::io;
+use adventofcode2025::aocutils::{parse_at, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<(bool, i32)>;
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        let mut rotations = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let left = match line.chars().next() {
+                Some('L') => true,
+                Some('R') => false,
+                _ => return Err(ParseError::at(i + 1, line, line, "expected a rotation starting with L or R").into()),
+            };
+            let amount: i32 = parse_at(i + 1, line, &line[1..], "a rotation amount")?;
+
+            rotations.push((left, amount));
+        }
+
+        Ok(rotations)
+    }
+
+    fn part1(_input: &Self::Input) -> impl Display {
+        "unsolved"
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let mut num_zero: u32 = 0;
+        let mut dial: i32 = 50;
+
+        for &(left, amount) in input {
+            rotate(left, amount, &mut dial, &mut num_zero);
+        }
+
+        num_zero
+    }
+}
+
+fn rotate(left: bool, amount: i32, dial: &mut i32, num_zero: &mut u32) {
+    let mut amount = amount;
+
+    while amount > 0 {
+        if left {
+            if *dial == 0 {
+                *dial = 99;
+            } else {
+                *dial -= 1;
+            }
+        } else if *dial == 99 {
+            *dial = 0
+        } else {
+                *dial += 1;
+        }
+
+        if *dial == 0 {
+            *num_zero += 1;
+        }
+
+        amount -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    adventofcode2025::example_tests!(Day1, part2);
+}
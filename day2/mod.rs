@@ -0,0 +1,190 @@
+use std::fmt::Display;
+use std::io;
+use adventofcode2025::aocutils::repetition::{sum_repeated, Rule};
+use adventofcode2025::aocutils::{parse_at, ParseError, Part, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        let mut ranges = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            for id in line.split(',') {
+                let (left, right) = id.split_once('-')
+                    .ok_or_else(|| ParseError::at(i + 1, line, id, "expected a range like 11-22"))?;
+                ranges.push((
+                    parse_at(i + 1, line, left, "an integer")?,
+                    parse_at(i + 1, line, right, "an integer")?,
+                ));
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        sum_invalid(input, rule_for(Part::One))
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        sum_invalid(input, rule_for(Part::Two))
+    }
+}
+
+fn rule_for(part: Part) -> Rule {
+    match part {
+        Part::One => Rule::Exactly(2),
+        Part::Two => Rule::AtLeast(2),
+    }
+}
+
+fn sum_invalid(ranges: &[(u64, u64)], rule: Rule) -> u128 {
+    ranges.iter()
+        .map(|&(start, end)| sum_repeated(start as u128, end as u128, 10, |reps| rule.accepts(reps)))
+        .sum::<Option<u128>>()
+        // Every ID below 2^64 appears at most once, so the total stays far below 2^128
+        .expect("sum of u64 IDs overflowed")
+}
+
+#[cfg(test)]
+fn check_range(start: u64, end: u64, rule: Rule, sum: &mut u64) {
+    for i in start..=end {
+        if rule.matches(i as u128, 10) {
+             *sum += i;
+        }
+    }
+}
+
+#[cfg(test)]
+fn is_invalid_id_part_1(id: u64) -> bool {
+    let id_str = id.to_string();
+    let len = id_str.len();
+
+    if !len.is_multiple_of(2) {
+        return false
+    }
+    let half = len / 2;
+    id_str[..half] == id_str[half..]
+}
+
+#[cfg(test)]
+fn is_invalid_id_part_2(id: u64) -> bool {
+    let id_str = id.to_string();
+    let len = id_str.len();
+
+    for sub_len in 1..=len/2 {
+        if !len.is_multiple_of(sub_len) {
+            continue;
+        }
+
+        let pattern = &id_str[..sub_len];
+
+        let mut i = sub_len;
+        while i < len {
+            if &id_str[i..i + sub_len] != pattern {
+                break;
+            }
+            i += sub_len;
+        }
+
+        if i == len {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use adventofcode2025::aocutils::xorshift::XorShift64;
+    use super::*;
+
+    adventofcode2025::example_tests!(Day2);
+
+    #[test]
+    fn test_invalid_id_part_1() {
+        assert!(is_invalid_id_part_1(11));
+        assert!(!is_invalid_id_part_1(12));
+
+        assert!(!is_invalid_id_part_1(111));
+        assert!(!is_invalid_id_part_1(112));
+
+        assert!(is_invalid_id_part_1(112112));
+        assert!(!is_invalid_id_part_1(1121122));
+    }
+
+    #[test]
+    fn test_invalid_id_part_2() {
+        assert!(is_invalid_id_part_2(11));
+        assert!(!is_invalid_id_part_2(12));
+
+        assert!(is_invalid_id_part_2(111));
+        assert!(!is_invalid_id_part_2(112));
+
+        assert!(is_invalid_id_part_2(112112));
+        assert!(!is_invalid_id_part_2(1121122));
+
+        assert!(is_invalid_id_part_2(11111111111));
+        assert!(is_invalid_id_part_2(1212121212));
+        assert!(is_invalid_id_part_2(123412341234));
+    }
+
+    #[test]
+    fn test_sum_repeated_matches_brute_force() {
+        for (start, end) in [(1, 10_000), (95, 115), (998, 1012), (222220, 222224), (1188511880, 1188511890)] {
+            for rule in [Rule::Exactly(2), Rule::AtLeast(2)] {
+                let mut sum = 0;
+                check_range(start, end, rule, &mut sum);
+                assert_eq!(sum_invalid(&[(start, end)], rule), sum as u128);
+            }
+        }
+    }
+
+    /// A fixed pseudo-random sequence of IDs, so the property tests are reproducible.
+    fn sample_ids(count: usize) -> impl Iterator<Item = u64> {
+        XorShift64::new(0x9e3779b97f4a7c15).take(count).enumerate().map(|(i, state)| {
+            // Mix in IDs built by repeating a short block, which random numbers almost never are
+            let block = state % 10u64.pow(1 + (i % 4) as u32);
+            match i % 2 {
+                0 => state >> (i % 60),
+                _ => format!("{}", block).repeat(2 + i % 3).parse().unwrap_or(block),
+            }
+        })
+    }
+
+    #[test]
+    fn test_part1_invalid_implies_part2_invalid() {
+        for id in (1..100_000).chain(sample_ids(10_000)) {
+            if is_invalid_id_part_1(id) {
+                assert!(is_invalid_id_part_2(id), "{} is invalid for part 1 only", id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_part1_sum_bounded_by_part2_sum() {
+        let ids: Vec<u64> = sample_ids(2_000).collect();
+        for pair in ids.chunks(2) {
+            let (start, end) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            assert!(sum_invalid(&[(start, end)], Rule::Exactly(2)) <= sum_invalid(&[(start, end)], Rule::AtLeast(2)));
+
+            // Single-ID ranges check the implication through the engine too
+            let halves = sum_invalid(&[(start, start)], Rule::Exactly(2));
+            assert!(halves == 0 || halves == sum_invalid(&[(start, start)], Rule::AtLeast(2)));
+        }
+    }
+
+    #[test]
+    fn test_rules_match_digit_comparison() {
+        for id in (1..100_000).chain(sample_ids(10_000)) {
+            assert_eq!(rule_for(Part::One).matches(id as u128, 10), is_invalid_id_part_1(id), "{}", id);
+            assert_eq!(rule_for(Part::Two).matches(id as u128, 10), is_invalid_id_part_2(id), "{}", id);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+use std::io::{self, ErrorKind};
+use adventofcode2025::aocutils::bignum::BigUint;
+use adventofcode2025::aocutils::digits::Query;
+use adventofcode2025::aocutils::{ParseError, Settings, Solution};
+
+/// The battery banks, one line of digits each, and how many digits part 2 picks from each.
+pub struct Banks {
+    lines: Vec<String>,
+    digits: usize,
+}
+
+impl Banks {
+
+    /// Checks every line is long enough for both parts to pick their digits from.
+    fn new(lines: Vec<String>, digits: usize) -> io::Result<Banks> {
+        let needed = digits.max(2);
+        if let Some((i, line)) = lines.iter().enumerate().find(|(_, line)| line.len() < needed) {
+            return Err(ParseError::new(i + 1, line, format!("expected at least {} digits", needed)).into());
+        }
+        Ok(Banks { lines, digits })
+    }
+}
+
+fn parse_lines(input: &str) -> io::Result<Vec<String>> {
+    let mut lines = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            return Err(ParseError::new(i + 1, line, "expected a line of digits").into());
+        }
+        if let Some((pos, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::at(i + 1, line, &line[pos..pos + c.len_utf8()], "expected only digits").into());
+        }
+        lines.push(line.to_owned());
+    }
+
+    Ok(lines)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Banks;
+
+    const SETTINGS: &'static [&'static str] = &["digits"];
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        Self::parse_with(input, &Settings::new())
+    }
+
+    fn parse_with(input: &str, settings: &Settings) -> io::Result<Self::Input> {
+        let digits = settings.get("digits")?.unwrap_or(12);
+        if digits == 0 {
+            return Err(io::Error::new(ErrorKind::InvalidInput, "--digits must be at least 1"));
+        }
+        Banks::new(parse_lines(input)?, digits)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        sum_highest(&input.lines, 2).expect("parsing checks every line is long enough")
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        sum_highest(&input.lines, input.digits).expect("parsing checks every line is long enough")
+    }
+}
+
+/// The `k`-digit subnumber of a line that `query` asks for, as a decimal string of any length.
+fn find_subnumber(raw: &str, k: usize, query: Query) -> Option<String> {
+    query.select(raw.as_bytes(), k).map(|selection| selection.digits().to_owned())
+}
+
+#[cfg(test)]
+fn find_highest_subnumber(raw: &str, k: usize) -> String {
+    find_subnumber(raw, k, Query::Highest).unwrap()
+}
+
+/// Sums the subnumbers `query` picks from each line, failing on the first line that
+/// has none rather than leaving it out.
+fn sum_query(lines: &[String], k: usize, query: Query) -> Result<BigUint, ParseError> {
+    lines.iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let digits = find_subnumber(line, k, query).ok_or_else(|| match line.len() < k {
+                true => ParseError::new(i + 1, line, format!("expected at least {} digits", k)),
+                false => ParseError::new(i + 1, line, format!("expected a {:?} subnumber of {} digits", query, k)),
+            })?;
+            Ok(BigUint::from_decimal(&digits).unwrap_or_default())
+        })
+        .sum()
+}
+
+/// Sums the highest `k`-digit subnumber of each line, failing on a line shorter than `k`
+/// rather than summing fewer digits from it.
+fn sum_highest(lines: &[String], k: usize) -> Result<BigUint, ParseError> {
+    sum_query(lines, k, Query::Highest)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::fs;
+    use super::*;
+
+    adventofcode2025::example_tests!(Day3);
+
+    #[test]
+    fn test_find_highest_subnumber_part_1() {
+        assert_eq!(find_highest_subnumber("72111111111", 2), "72");
+        assert_eq!(find_highest_subnumber("99000000000", 2), "99");
+        assert_eq!(find_highest_subnumber("76543217699", 2), "99");
+        assert_eq!(find_highest_subnumber("90000000009", 2), "99");
+    }
+
+    #[test]
+    fn test_find_highest_subnumber_part_2() {
+        assert_eq!(find_highest_subnumber("987654321111111", 12), "987654321111");
+        assert_eq!(find_highest_subnumber("811111111111119", 12), "811111111119");
+        assert_eq!(find_highest_subnumber("234234234234278", 12), "434234234278");
+        assert_eq!(find_highest_subnumber("818181911112111", 12), "888911112111");
+    }
+
+    #[test]
+    fn test_sum_many_digits() {
+        // Well past what a u64, or even a u128, can hold
+        let lines = vec!["9".repeat(300), format!("1{}", "0".repeat(299))];
+        let sum = sum_highest(&lines, 250).unwrap();
+        assert_eq!(sum.to_string(), format!("10{}", "9".repeat(249)));
+
+        let lines = Day3::parse("987654321111111\n811111111111119").unwrap().lines;
+        assert_eq!(sum_highest(&lines, 15).unwrap().to_string(), "1798765432222230");
+        let err = sum_highest(&lines, 40).unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1: expected at least 40 digits, found \"987654321111111\"");
+    }
+
+    #[test]
+    fn test_queries() {
+        let lines = Day3::parse(&fs::read_to_string("day3/testdata/input_part_1.txt").unwrap()).unwrap().lines;
+        assert_eq!(find_subnumber(&lines[0], 12, Query::Lowest).unwrap(), "654321111111");
+        assert_eq!(find_subnumber(&lines[2], 4, Query::Lowest).unwrap(), "2222");
+        assert_eq!(find_subnumber(&lines[3], 3, Query::HighestDivisible(7)).unwrap(), "889");
+        assert_eq!(find_subnumber(&lines[1], 2, Query::NthHighest(1)).unwrap(), "81");
+        assert_eq!(find_subnumber(&lines[1], 2, Query::NthHighest(4)), None);
+
+        assert_eq!(sum_query(&lines, 12, Query::NthHighest(0)).ok(), sum_highest(&lines, 12).ok());
+        let err = sum_query(&lines, 15, Query::HighestDivisible(2)).unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1: expected a HighestDivisible(2) subnumber of 15 digits, found \"987654321111111\"");
+        assert_eq!(sum_query(&lines, 2, Query::HighestDivisible(9)).unwrap().to_string(), "270");
+    }
+
+    #[test]
+    fn test_digits_setting() {
+        let raw = fs::read_to_string("day3/testdata/input_part_1.txt").unwrap();
+        assert_eq!(Day3::parse(&raw).unwrap().digits, 12);
+
+        let mut settings = Settings::new();
+        settings.set("digits", "15");
+        let banks = Day3::parse_with(&raw, &settings).unwrap();
+        assert_eq!(Day3::part2(&banks).to_string(), sum_highest(&banks.lines, 15).unwrap().to_string());
+
+        // A line too short for the digits asked for is reported rather than summed whole
+        settings.set("digits", "16");
+        let err = Day3::parse_with(&raw, &settings).err().unwrap();
+        assert_eq!(err.to_string(), "<input>:1: expected at least 16 digits, found \"987654321111111\"");
+        assert_eq!(Day3::parse("9").err().unwrap().to_string(), "<input>:1: expected at least 12 digits, found \"9\"");
+
+        settings.set("digits", "0");
+        assert_eq!(Day3::parse_with(&raw, &settings).err().unwrap().to_string(), "--digits must be at least 1");
+        settings.set("digits", "x");
+        assert_eq!(Day3::parse_with(&raw, &settings).err().unwrap().to_string(), "invalid value for --digits: x");
+    }
+}
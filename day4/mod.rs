@@ -0,0 +1,372 @@
+use std::fmt::Display;
+use std::io::{self, ErrorKind};
+use std::str::FromStr;
+use adventofcode2025::aocutils::grid::{Grid, Neighborhood};
+use adventofcode2025::aocutils::{Settings, Solution};
+
+/// How a cell's neighbor count is compared against a [`Rule`]'s threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+impl Comparison {
+
+    /// Whether `count` relates to `threshold` as this comparison asks.
+    pub fn apply(self, count: usize, threshold: usize) -> bool {
+        match self {
+            Comparison::Lt => count < threshold,
+            Comparison::Le => count <= threshold,
+            Comparison::Eq => count == threshold,
+            Comparison::Ge => count >= threshold,
+            Comparison::Gt => count > threshold,
+        }
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Comparison> {
+        match s {
+            "<" | "lt" => Ok(Comparison::Lt),
+            "<=" | "le" => Ok(Comparison::Le),
+            "=" | "==" | "eq" => Ok(Comparison::Eq),
+            ">=" | "ge" => Ok(Comparison::Ge),
+            ">" | "gt" => Ok(Comparison::Gt),
+            _ => Err(io::Error::new(ErrorKind::InvalidInput, format!("invalid comparison: {}", s))),
+        }
+    }
+}
+
+/// When a cell counts as accessible: it holds `symbol`, and the number of its
+/// neighbors that hold `symbol` too passes `comparison` against `threshold`.
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    pub symbol: char,
+    pub neighborhood: Neighborhood,
+    pub threshold: usize,
+    pub comparison: Comparison,
+    /// Whether neighbors wrap around the edges of the grid. Each neighbor still
+    /// counts once, and a cell is never its own neighbor, even on a grid smaller
+    /// than the neighborhood.
+    pub toroidal: bool,
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self {
+            symbol: '@',
+            neighborhood: Neighborhood::Moore,
+            threshold: 4,
+            comparison: Comparison::Lt,
+            toroidal: false,
+        }
+    }
+}
+
+impl Rule {
+
+    fn accepts(&self, count: usize) -> bool {
+        self.comparison.apply(count, self.threshold)
+    }
+
+    /// Offsets to the neighbors of any cell of `grid`. When wrapping, offsets are
+    /// reduced modulo the grid's size, so those reaching the same cell, or the cell
+    /// itself, are merged or dropped.
+    fn offsets(&self, grid: &Grid<char>) -> Vec<(isize, isize)> {
+        let offsets = self.neighborhood.offsets();
+        if !self.toroidal {
+            return offsets;
+        }
+
+        let (height, width) = (grid.height().max(1) as isize, grid.width().max(1) as isize);
+        let mut wrapped: Vec<(isize, isize)> = offsets.into_iter()
+            .map(|(dr, dc)| (dr.rem_euclid(height), dc.rem_euclid(width)))
+            .filter(|&delta| delta != (0, 0))
+            .collect();
+        wrapped.sort_unstable();
+        wrapped.dedup();
+        wrapped
+    }
+
+    fn neighbors<'a>(&self, grid: &'a Grid<char>, offsets: &'a [(isize, isize)], pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let toroidal = self.toroidal;
+        offsets.iter().filter_map(move |&delta| match toroidal {
+            true => Some(grid.offset_wrapping(pos, delta)),
+            false => grid.offset(pos, delta),
+        })
+    }
+}
+
+/// The grid of paper rolls, and the rule both parts use to find the accessible ones.
+pub struct Floor {
+    grid: Grid<char>,
+    rule: Rule,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Floor;
+
+    const SETTINGS: &'static [&'static str] = &["symbol", "neighborhood", "threshold", "comparison"];
+    const FLAGS: &'static [&'static str] = &["toroidal"];
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        Self::parse_with(input, &Settings::new())
+    }
+
+    fn parse_with(input: &str, settings: &Settings) -> io::Result<Self::Input> {
+        let default = Rule::default();
+        let rule = Rule {
+            symbol: settings.get("symbol")?.unwrap_or(default.symbol),
+            neighborhood: settings.get("neighborhood")?.unwrap_or(default.neighborhood),
+            threshold: settings.get("threshold")?.unwrap_or(default.threshold),
+            comparison: settings.get("comparison")?.unwrap_or(default.comparison),
+            toroidal: settings.is_set("toroidal"),
+        };
+        Ok(Floor { grid: Grid::parse(input)?, rule })
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        get_positions(&input.grid, &input.rule).len()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        remove_all(&input.grid, &input.rule).0
+    }
+}
+
+fn check_position(grid: &Grid<char>, rule: &Rule, offsets: &[(isize, isize)], row: usize, col: usize) -> bool {
+    if grid[(row, col)] != rule.symbol {
+        return false;
+    }
+
+    let papers = rule.neighbors(grid, offsets, (row, col))
+        .filter(|&pos| grid[pos] == rule.symbol)
+        .count();
+
+    rule.accepts(papers)
+}
+
+fn get_positions(grid: &Grid<char>, rule: &Rule) -> Vec<(usize, usize)> {
+    let offsets = rule.offsets(grid);
+    grid.positions()
+        .filter(|&(row, col)| check_position(grid, rule, &offsets, row, col))
+        .collect()
+}
+
+/// Removes accessible cells wave by wave until none is left, returning the total
+/// removed and the wave, counting from 1, in which each cell was removed. Neighbor
+/// counts are kept up to date, so only the neighbors of removed cells are re-examined.
+fn remove_all(grid: &Grid<char>, rule: &Rule) -> (u64, Grid<Option<u32>>) {
+    let (width, height) = (grid.width(), grid.height());
+    let offsets = rule.offsets(grid);
+    let mut present = Grid::from_vec(width, height, grid.iter().map(|(_, &c)| c == rule.symbol).collect()).unwrap();
+    let mut counts = Grid::new(width, height, 0);
+    for pos in grid.find_all(&rule.symbol) {
+        counts[pos] = rule.neighbors(grid, &offsets, pos).filter(|&pos| present[pos]).count();
+    }
+
+    let mut waves = Grid::new(width, height, None);
+    // The last wave in which each cell's count changed, so it is only rechecked once
+    let mut touched = Grid::new(width, height, 0);
+    let mut total = 0;
+    let mut wave = get_positions(grid, rule);
+    let mut number = 0;
+    while !wave.is_empty() {
+        number += 1;
+        total += wave.len() as u64;
+        for &pos in &wave {
+            present[pos] = false;
+            waves[pos] = Some(number);
+        }
+
+        // A cell whose count did not change was not accessible before, so still is not
+        let mut changed = Vec::new();
+        for &pos in &wave {
+            for pos in rule.neighbors(grid, &offsets, pos).filter(|&pos| present[pos]) {
+                counts[pos] -= 1;
+                if touched[pos] != number {
+                    touched[pos] = number;
+                    changed.push(pos);
+                }
+            }
+        }
+        wave = changed.into_iter().filter(|&pos| rule.accepts(counts[pos])).collect();
+    }
+
+    (total, waves)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::fs;
+    use adventofcode2025::aocutils::xorshift::XorShift64;
+    use super::*;
+
+    adventofcode2025::example_tests!(Day4);
+
+    /// The original simulation, rescanning the whole grid until nothing changes.
+    fn remove_by_rescanning(input: &Grid<char>, rule: &Rule) -> (u64, Grid<Option<u32>>) {
+        let mut grid = input.clone();
+        let mut waves = Grid::new(grid.width(), grid.height(), None);
+
+        let mut total: u64 = 0;
+        for number in 1.. {
+            let positions = get_positions(&grid, rule);
+            if positions.is_empty() {
+                break;
+            }
+            total += positions.len() as u64;
+
+            for &pos in positions.as_slice() {
+                grid[pos] = '.';
+                waves[pos] = Some(number);
+            }
+        }
+
+        (total, waves)
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let grid = Grid::parse(&fs::read_to_string("day4/testdata/input_part_1.txt").unwrap()).unwrap();
+        let (total, waves) = remove_all(&grid, &Rule::default());
+        assert_eq!((total, waves.clone()), remove_by_rescanning(&grid, &Rule::default()));
+        assert_eq!(total, 43);
+        assert_eq!(waves.iter().filter(|&(_, wave)| *wave == Some(1)).count(), 13);
+        assert_eq!(waves[(0, 0)], None);
+        assert_eq!(waves[(0, 2)], Some(1));
+    }
+
+    fn random_grids() -> impl Iterator<Item = Grid<char>> {
+        let mut rng = XorShift64::new(0x853c49e6748fea9b);
+        (1..20).map(move |size| {
+            // Dense grids, so that removal runs for several waves
+            let cells = rng.by_ref()
+                .take(size * (size + 1))
+                .map(|value| if value.is_multiple_of(5) { '.' } else { '@' });
+            Grid::from_vec(size + 1, size, cells.collect()).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_remove_all_matches_rescanning() {
+        let neighborhoods = [Neighborhood::VonNeumann, Neighborhood::Moore, Neighborhood::Radius(2)];
+        let comparisons = [Comparison::Lt, Comparison::Le, Comparison::Eq, Comparison::Ge, Comparison::Gt];
+        for grid in random_grids() {
+            for (neighborhood, comparison, toroidal) in combinations(neighborhoods, comparisons) {
+                for threshold in [2, 3, 5] {
+                    let rule = Rule { neighborhood, threshold, comparison, toroidal, ..Rule::default() };
+                    let (total, waves) = remove_all(&grid, &rule);
+                    assert_eq!((total, waves.clone()), remove_by_rescanning(&grid, &rule), "{:?}\n{}", rule, grid);
+                    // A single pass removes exactly the first wave
+                    let first = waves.iter().filter(|&(_, wave)| *wave == Some(1)).count();
+                    assert_eq!(get_positions(&grid, &rule).len(), first);
+                }
+            }
+        }
+    }
+
+    /// Every combination of neighborhood, comparison and wrapping.
+    fn combinations<N: Copy, C: Copy>(neighborhoods: [N; 3], comparisons: [C; 5]) -> impl Iterator<Item = (N, C, bool)> {
+        neighborhoods.into_iter().flat_map(move |n| {
+            comparisons.into_iter().flat_map(move |c| [(n, c, false), (n, c, true)])
+        })
+    }
+
+    #[test]
+    fn test_rules() {
+        let full = Grid::new(3, 3, '#');
+        let rule = Rule { symbol: '#', ..Rule::default() };
+        // Only the corners see fewer than 4 others, until the torus gives everyone 8
+        assert_eq!(get_positions(&full, &rule), [(0, 0), (0, 2), (2, 0), (2, 2)]);
+        assert!(get_positions(&full, &Rule { toroidal: true, ..rule }).is_empty());
+        assert!(get_positions(&full, &Rule::default()).is_empty());
+
+        // Everything goes once the corners have, wave by wave inwards
+        let (total, waves) = remove_all(&full, &rule);
+        assert_eq!(total, 9);
+        assert_eq!(waves[(0, 0)], Some(1));
+        assert_eq!(waves[(0, 1)], Some(2));
+        assert_eq!(waves[(1, 1)], Some(3));
+
+        // With a von Neumann neighborhood, at most 4 neighbors can ever be counted
+        let rule = Rule { neighborhood: Neighborhood::VonNeumann, threshold: 4, comparison: Comparison::Le, ..rule };
+        assert_eq!(remove_all(&full, &rule).0, 9);
+        assert_eq!(remove_all(&full, &Rule { comparison: Comparison::Gt, ..rule }).0, 0);
+    }
+
+    #[test]
+    fn test_rule_settings() {
+        let raw = fs::read_to_string("day4/testdata/input_part_1.txt").unwrap();
+        let floor = Day4::parse(&raw).unwrap();
+        assert_eq!((floor.rule.neighborhood, floor.rule.threshold, floor.rule.toroidal), (Neighborhood::Moore, 4, false));
+
+        let mut settings = Settings::new();
+        settings.set("symbol", ".");
+        settings.set("neighborhood", "vn");
+        settings.set("threshold", "2");
+        settings.set("comparison", "ge");
+        settings.set("toroidal", "true");
+        let floor = Day4::parse_with(&raw, &settings).unwrap();
+        let rule = Rule {
+            symbol: '.',
+            neighborhood: Neighborhood::VonNeumann,
+            threshold: 2,
+            comparison: Comparison::Ge,
+            toroidal: true,
+        };
+        assert_eq!(Day4::part1(&floor).to_string(), get_positions(&floor.grid, &rule).len().to_string());
+        assert_eq!(Day4::part2(&floor).to_string(), remove_all(&floor.grid, &rule).0.to_string());
+
+        settings.set("neighborhood", "hex");
+        assert_eq!(Day4::parse_with(&raw, &settings).err().unwrap().to_string(), "invalid value for --neighborhood: hex");
+        settings.set("neighborhood", "r2");
+        settings.set("symbol", "@@");
+        assert_eq!(Day4::parse_with(&raw, &settings).err().unwrap().to_string(), "invalid value for --symbol: @@");
+    }
+
+    #[test]
+    fn test_comparison() {
+        let parsed: Vec<Comparison> = ["<", "<=", "==", ">=", ">"].iter().map(|s| s.parse().unwrap()).collect();
+        assert_eq!(parsed, [Comparison::Lt, Comparison::Le, Comparison::Eq, Comparison::Ge, Comparison::Gt]);
+        assert_eq!("ge".parse::<Comparison>().unwrap(), Comparison::Ge);
+        assert_eq!("<>".parse::<Comparison>().unwrap_err().to_string(), "invalid comparison: <>");
+
+        let passing: Vec<usize> = (0..6).filter(|&count| Comparison::Le.apply(count, 3)).collect();
+        assert_eq!(passing, [0, 1, 2, 3]);
+        assert!(Comparison::Eq.apply(3, 3) && !Comparison::Gt.apply(3, 3) && Comparison::Ge.apply(3, 3));
+    }
+
+    #[test]
+    fn test_toroidal_small_grids() {
+        let rule = Rule { threshold: 3, toroidal: true, ..Rule::default() };
+
+        // In a single row, the Moore neighborhood wraps onto just the cells either side
+        let row = Grid::new(5, 1, '@');
+        assert_eq!(rule.offsets(&row), [(0, 1), (0, 4)]);
+        assert_eq!(get_positions(&row, &rule).len(), 5);
+
+        // A lone cell has no neighbors at all, however far the neighborhood reaches
+        let cell = Grid::new(1, 1, '@');
+        assert!(rule.offsets(&cell).is_empty());
+        let rule = Rule { neighborhood: Neighborhood::Radius(3), threshold: 1, ..rule };
+        assert_eq!(get_positions(&cell, &rule), [(0, 0)]);
+
+        // Every other cell of a 3x3 torus is in reach, once each
+        let full = Grid::new(3, 3, '@');
+        assert_eq!(rule.offsets(&full).len(), 8);
+        let rule = Rule { threshold: 8, comparison: Comparison::Eq, ..rule };
+        assert_eq!(get_positions(&full, &rule).len(), 9);
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt::Display;
+use std::io::{self, BufRead, ErrorKind, Write};
+use std::str::FromStr;
+use adventofcode2025::aocutils::intervals::IntervalSet;
+use adventofcode2025::aocutils::{parse_at, ParseError, Solution};
+
+pub struct FreshDB {
+    fresh_ranges: IntervalSet<u64>,
+    test_values: Vec<u64>,
+}
+
+fn parse_range(line_no: usize, text: &str) -> io::Result<(u64, u64)> {
+    let line = text.trim();
+    let (start, end) = line
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(line_no, text, line, "expected a range like 3-5"))?;
+
+    let start: u64 = parse_at(line_no, text, start, "an integer")?;
+    let end: u64 = parse_at(line_no, text, end, "an integer")?;
+    if start > end {
+        return Err(ParseError::at(line_no, text, line, "expected the range start to not exceed its end").into());
+    }
+    Ok((start, end))
+}
+
+fn parse_value(line_no: usize, text: &str) -> io::Result<u64> {
+    Ok(parse_at(line_no, text, text.trim(), "an integer")?)
+}
+
+impl FreshDB {
+    fn new(raw: &str) -> io::Result<FreshDB> {
+        let mut ranges = IntervalSet::new();
+        let mut values = Vec::new();
+
+        let mut in_values = false;
+
+        for (i, text) in raw.lines().enumerate() {
+            if text.trim().is_empty() {
+                in_values = true;
+                continue;
+            }
+
+            if !in_values {
+                let (start, end) = parse_range(i + 1, text)?;
+                ranges.insert(start, end);
+            } else {
+                values.push(parse_value(i + 1, text)?);
+            }
+        }
+
+        Ok(Self {
+            fresh_ranges: ranges,
+            test_values: values,
+        })
+    }
+
+    fn test_freshness(&self) -> u64 {
+        self.test_values.iter()
+            .filter(|&&value| self.fresh_ranges.contains(value))
+            .count() as u64
+    }
+
+    fn count_total(&self) -> u128 {
+        self.fresh_ranges.total_len()
+    }
+}
+
+/// Which IDs [`stream`] writes out as it classifies them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Listing {
+    Fresh,
+    Spoiled,
+}
+
+impl FromStr for Listing {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Listing> {
+        match s {
+            "fresh" => Ok(Listing::Fresh),
+            "spoiled" => Ok(Listing::Spoiled),
+            _ => Err(io::Error::new(ErrorKind::InvalidInput, format!("invalid listing: {}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamCounts {
+    pub fresh: u64,
+    pub spoiled: u64,
+}
+
+/// Reads the ranges from `reader`, then classifies the IDs after them one line at a
+/// time, so memory stays proportional to the number of ranges however many IDs follow.
+/// With a `listing`, every ID of that kind is written to `out`, one per line.
+pub fn stream(mut reader: impl BufRead, listing: Option<Listing>, out: &mut impl Write) -> io::Result<StreamCounts> {
+    let mut ranges = IntervalSet::new();
+    let mut counts = StreamCounts::default();
+    let mut in_values = false;
+
+    let mut buf = String::new();
+    let mut line_no = 0;
+    while reader.read_line(&mut buf)? > 0 {
+        line_no += 1;
+        let text = buf.trim_end_matches(['\n', '\r']);
+
+        if text.trim().is_empty() {
+            in_values = true;
+        } else if !in_values {
+            let (start, end) = parse_range(line_no, text)?;
+            ranges.insert(start, end);
+        } else {
+            let value = parse_value(line_no, text)?;
+            let kind = match ranges.contains(value) {
+                true => {
+                    counts.fresh += 1;
+                    Listing::Fresh
+                }
+                false => {
+                    counts.spoiled += 1;
+                    Listing::Spoiled
+                }
+            };
+            if listing == Some(kind) {
+                writeln!(out, "{}", value)?;
+            }
+        }
+        buf.clear();
+    }
+
+    Ok(counts)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = FreshDB;
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        FreshDB::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.test_freshness()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input.count_total()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::fs;
+    use super::*;
+
+    adventofcode2025::example_tests!(Day5);
+
+    #[test]
+    fn test_collapse_ranges() {
+        let db = FreshDB::new(&fs::read_to_string("day5/testdata/input_part_1.txt").unwrap()).unwrap();
+        // 3-5 10-20
+        assert_eq!(db.fresh_ranges.ranges(), &[(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_adjacent_and_empty_ranges() {
+        let db = FreshDB::new("3-5\n6-8\n\n6").unwrap();
+        assert_eq!(db.fresh_ranges.ranges(), &[(3, 8)]);
+        assert_eq!(db.count_total(), 6);
+
+        let db = FreshDB::new("0-18446744073709551615\n\n1").unwrap();
+        assert_eq!(db.count_total(), 1 << 64);
+
+        let db = FreshDB::new("\n1\n2").unwrap();
+        assert_eq!(db.test_freshness(), 0);
+        assert_eq!(db.count_total(), 0);
+    }
+
+    #[test]
+    fn test_stream() {
+        let raw = fs::read_to_string("day5/testdata/input_part_1.txt").unwrap();
+        let mut out = Vec::new();
+        let counts = stream(raw.as_bytes(), None, &mut out).unwrap();
+        assert_eq!(counts, StreamCounts { fresh: 3, spoiled: 3 });
+        assert!(out.is_empty());
+
+        stream(raw.as_bytes(), Some(Listing::Fresh), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "5\n11\n17\n");
+
+        let mut out = Vec::new();
+        stream(raw.as_bytes(), Some(Listing::Spoiled), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "1\n8\n32\n");
+    }
+
+    #[test]
+    fn test_stream_error() {
+        let err = stream("3-5\r\n\r\n4\r\nx\r\n".as_bytes(), None, &mut io::sink()).err().unwrap();
+        assert_eq!(err.to_string(), "<input>:4:1: expected an integer, found \"x\"");
+    }
+
+    #[test]
+    fn test_malformed_range() {
+        let err = FreshDB::new("3-5\n10_14\n\n1").err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "<input>:2:1: expected a range like 3-5, found \"10_14\"");
+    }
+
+}
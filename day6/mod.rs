@@ -0,0 +1,167 @@
+use std::fmt::Display;
+use std::io;
+use adventofcode2025::aocutils::{parse_at, CondRev, ParseError, Solution};
+
+pub struct MathTable {
+    numbers: Vec<Vec<i64>>,
+    symbols: Vec<char>,
+}
+
+impl MathTable {
+
+    fn new(raw: &str, reversed: bool) -> io::Result<MathTable> {
+        let mut lines = raw.lines().collect::<Vec<_>>();
+
+        let symbol_line = lines.pop()
+            .ok_or_else(|| ParseError::new(1, "", "expected a line of operators"))?;
+        let symbol_line_no = lines.len() + 1;
+        if lines.is_empty() {
+            return Err(ParseError::new(1, symbol_line, "expected at least one row of numbers").into());
+        }
+
+        let symbols = symbol_line.split_whitespace()
+            .map(|s| match s {
+                "+" => Ok('+'),
+                "*" => Ok('*'),
+                _ => Err(ParseError::at(symbol_line_no, symbol_line, s, "expected + or *")),
+            })
+            .cond_rev(reversed)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let numbers = match reversed {
+            true => MathTable::read_data_reversed(lines)?,
+            false => MathTable::read_data(lines)?,
+        };
+
+        if numbers.len() != symbols.len() {
+            let message = format!("expected {} operators", numbers.len());
+            return Err(ParseError::new(symbol_line_no, symbol_line, message).into());
+        }
+
+        Ok(MathTable { numbers, symbols })
+    }
+
+    fn read_data(lines: Vec<&str>) -> Result<Vec<Vec<i64>>, ParseError> {
+        let rows: Vec<Vec<i64>> = lines.iter()
+            .enumerate()
+            .map(|(i, line)| line.split_whitespace()
+                .map(|s| parse_at(i + 1, line, s, "an integer"))
+                .collect::<Result<Vec<_>, _>>())
+            .collect::<Result<_, _>>()?;
+
+        let width = rows[0].len();
+        if let Some(i) = rows.iter().position(|row| row.len() != width) {
+            return Err(ParseError::new(i + 1, lines[i], format!("expected {} numbers", width)));
+        }
+
+        Ok(rows.iter().fold(
+            vec![Vec::with_capacity(rows.len()); width],
+            |mut acc, row| {
+                (0..width).for_each(|c| acc[c].push(row[c]));
+                acc
+            }))
+    }
+
+    fn read_data_reversed(lines: Vec<&str>) -> Result<Vec<Vec<i64>>, ParseError> {
+        for (i, line) in lines.iter().enumerate() {
+            if let Some((pos, c)) = line.char_indices().find(|&(_, c)| !c.is_ascii_digit() && c != ' ') {
+                return Err(ParseError::at(i + 1, line, &line[pos..pos + c.len_utf8()], "expected digits or spaces"));
+            }
+        }
+
+        // Lines may have had trailing spaces trimmed, so pad them back out
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        (0..width)
+            .rev()
+            .map(|i| lines.iter()
+                .map(|line| line.chars().nth(i).unwrap_or(' '))
+                .collect::<String>())
+            .enumerate()
+            .try_fold(Vec::new(), |mut acc: Vec<Vec<i64>>, (col, s)| {
+                if s.trim().is_empty() {
+                    acc.push(Vec::new());
+                } else {
+                    if acc.is_empty() {
+                        acc.push(Vec::new());
+                    }
+                    acc.last_mut()
+                        .unwrap()
+                        .push(s.trim()
+                            .parse::<i64>()
+                            .map_err(|_| ParseError::new(1, &s, "expected one number per column")
+                                .with_column(width - col))?);
+                }
+                Ok(acc)
+            })
+    }
+
+    fn size(&self) -> usize {
+        self.symbols.len()
+    }
+
+    fn sum(&self) -> i64 {
+        (0..self.size())
+            .map(|i| (&self.numbers[i], self.symbols[i]))
+            .map(|(numbers, symbol)| numbers.iter()
+                .copied()
+                .reduce(|acc, n| match symbol {
+                    '+' => acc + n,
+                    '*' => acc * n,
+                    _ => unreachable!(),
+                })
+                .unwrap_or(0))
+            .sum()
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = (MathTable, MathTable);
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        Ok((MathTable::new(input, false)?, MathTable::new(input, true)?))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.0.sum()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input.1.sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::fs;
+    use adventofcode2025::aocutils::cmp_vec;
+    use super::*;
+
+    adventofcode2025::example_tests!(Day6);
+
+    #[test]
+    fn test_standard_parse() {
+        let table = MathTable::new(&fs::read_to_string("day6/testdata/input_part_1.txt").unwrap(), false).unwrap();
+        assert_eq!(table.size(), 4);
+        assert!(cmp_vec(&table.numbers[0], &[123, 45, 6]));
+        assert!(cmp_vec(&table.numbers[1], &[328, 64, 98]));
+        assert!(cmp_vec(&table.numbers[2], &[51, 387, 215]));
+        assert!(cmp_vec(&table.numbers[3], &[64, 23, 314]));
+    }
+
+    #[test]
+    fn test_reversed_parse() {
+        let table = MathTable::new(&fs::read_to_string("day6/testdata/input_part_1.txt").unwrap(), true).unwrap();
+        assert_eq!(table.size(), 4);
+        assert!(cmp_vec(&table.numbers[0], &[4, 431, 623]));
+        assert!(cmp_vec(&table.numbers[1], &[175, 581, 32]));
+        assert!(cmp_vec(&table.numbers[2], &[8, 248, 369]));
+        assert!(cmp_vec(&table.numbers[3], &[356, 24, 1]));
+    }
+
+}
@@ -1,46 +1,48 @@
 use std::collections::HashMap;
-use std::fs::File;
+use std::fmt::Display;
 use std::io;
-use std::io::{BufRead, BufReader};
-use adventofcode2025::aocutils::RunTimer;
+use adventofcode2025::aocutils::grid::Grid;
+use adventofcode2025::aocutils::{ParseError, Solution};
 
-struct Manifold {
-    manifold: Vec<String>
+pub struct Manifold {
+    manifold: Grid<char>
 }
 
 impl Manifold {
 
-    fn new(file: &str) -> io::Result<Manifold> {
-        let file = File::open(file)?;
-        let reader = BufReader::new(file);
-        let manifold = reader.lines()
-            .map_while(Result::ok)
-            .collect();
+    fn new(raw: &str) -> io::Result<Manifold> {
+        let manifold = Grid::parse_with(raw, "expected ., ^ or S", |c| matches!(c, '.' | '^' | 'S').then_some(c))?;
+
+        let first = raw.lines().next().unwrap_or("");
+        if manifold.height() == 0 || !manifold.row(0).contains(&'S') {
+            return Err(ParseError::new(1, first, "expected a start position S").into());
+        }
+        if manifold.height() < 2 {
+            return Err(ParseError::new(1, first, "expected at least two rows").into());
+        }
 
         Ok(Manifold { manifold })
     }
 
     fn start_pos(&self) -> usize {
-        self.manifold[0].find("S").unwrap()
+        self.manifold.row(0).iter().position(|&c| c == 'S').unwrap()
     }
 
     fn calc_beams(&self) -> (usize, usize) {
         let mut splits: usize = 0;
 
         let start_pos = self.start_pos();
-        let mut beams: Vec<HashMap<usize, usize>> = (0..self.manifold.len())
+        let mut beams: Vec<HashMap<usize, usize>> = (0..self.manifold.height())
             .map(|_| HashMap::new()).collect();
         beams[1].insert(start_pos, 1);
 
-        for i in 2..self.manifold.len() {
-            let line = &self.manifold[i];
-
+        for i in 2..self.manifold.height() {
             let (a, b) = beams.split_at_mut(i);
             let prev_beam = &a[i-1];
             let curr_beam = &mut b[0];
 
             for (&pos, &num) in prev_beam {
-                let char = line.chars().nth(pos).unwrap();
+                let char = self.manifold[(i, pos)];
                 if char == '.' {
                     curr_beam.entry(pos)
                         .and_modify(|x| *x += num)
@@ -56,7 +58,7 @@ impl Manifold {
                             .or_insert(num);
                     }
 
-                    if (right as usize) < line.len() {
+                    if (right as usize) < self.manifold.width() {
                         curr_beam.entry(right as usize)
                             .and_modify(|x| *x += num)
                             .or_insert(num);
@@ -69,41 +71,40 @@ impl Manifold {
     }
 }
 
-fn main() -> io::Result<()> {
-    let mut timer = RunTimer::new();
+pub struct Day7;
 
-    let manifold = Manifold::new("day7/input.txt")?;
-    let (splits, timelines) = manifold.calc_beams();
-    println!("Splits: {}", splits);
-    println!("Timelines: {}", timelines);
+impl Solution for Day7 {
+    const DAY: u8 = 7;
 
-    timer.finish();
+    type Input = Manifold;
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        Manifold::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (splits, _) = input.calc_beams();
+        splits
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input) -> impl Display {
+        let (_, timelines) = input.calc_beams();
+        timelines
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::fs;
     use super::*;
 
+    adventofcode2025::example_tests!(Day7);
+
     #[test]
     fn test_start_pos() {
-        let manifold = Manifold::new("day7/testdata/input_part_1.txt").unwrap();
+        let manifold = Manifold::new(&fs::read_to_string("day7/testdata/input_part_1.txt").unwrap()).unwrap();
         assert_eq!(manifold.start_pos(), 7);
     }
 
-    #[test]
-    fn test_calc_beams_splits() {
-        let manifold = Manifold::new("day7/testdata/input_part_1.txt").unwrap();
-        let (splits, _) = manifold.calc_beams();
-        assert_eq!(splits, 21);
-    }
-
-    #[test]
-    fn test_calc_beams_timelines() {
-        let manifold = Manifold::new("day7/testdata/input_part_1.txt").unwrap();
-        let (_, timelines) = manifold.calc_beams();
-        assert_eq!(timelines, 40);
-    }
 }
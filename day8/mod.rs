@@ -0,0 +1,186 @@
+use std::fmt::Display;
+use std::io;
+use adventofcode2025::aocutils::dsu::DisjointSet;
+use adventofcode2025::aocutils::geom::Point3;
+use adventofcode2025::aocutils::spatial::{euclidean_mst, nearest_pairs, Edge};
+use adventofcode2025::aocutils::{parse_at, ParseError, Solution};
+
+pub type Position = Point3<i32>;
+
+/// A candidate connection between two junction boxes, by index.
+type Link = Edge;
+
+struct Calculator {
+    positions: Vec<Position>,
+    links: Vec<Link>
+}
+
+impl Calculator {
+    /// Takes the `max_connections` shortest links.
+    fn new(positions: &[Position], max_connections: usize) -> Calculator {
+        let links = nearest_pairs(positions).take(max_connections).collect();
+        Calculator { positions: positions.to_vec(), links }
+    }
+
+    /// Connects the links in order, returning the circuit sizes from largest to
+    /// smallest and the product of the X coordinates of the link that joined
+    /// everything into one circuit, or 0 if the links never did.
+    fn calculate_circuits(&self) -> (Vec<usize>, u64) {
+        let mut unify_point: u64 = 0;
+        let mut circuits = DisjointSet::new(self.positions.len());
+
+        for link in self.links.iter() {
+            let (a, b) = link.ends;
+            if circuits.union(a, b) && circuits.components() == 1 {
+                unify_point = mult_x(&self.positions, link);
+                break;
+            }
+        }
+
+        let mut sizes = circuits.component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        (sizes, unify_point)
+    }
+}
+
+/// Product of the X coordinates of the two junction boxes that `link` connects.
+fn mult_x(positions: &[Position], link: &Link) -> u64 {
+    let (a, b) = link.ends;
+    positions[a].x as u64 * positions[b].x as u64
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Position>;
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        let mut positions = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let pos_raw: Vec<&str> = line.trim().split(',').collect();
+            if pos_raw.len() != 3 {
+                return Err(ParseError::new(i + 1, line, "expected 3 comma-separated integers").into());
+            }
+            positions.push(Position::new(
+                parse_at(i + 1, line, pos_raw[0], "an integer")?,
+                parse_at(i + 1, line, pos_raw[1], "an integer")?,
+                parse_at(i + 1, line, pos_raw[2], "an integer")?,
+            ));
+        }
+
+        if positions.is_empty() {
+            return Err(ParseError::new(1, "", "expected at least one junction box").into());
+        }
+
+        Ok(positions)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let calculator = Calculator::new(input, 1000);
+        let (sizes, _) = calculator.calculate_circuits();
+        sizes.iter()
+            .take(3)
+            .product::<usize>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        // The last tree edge is the link that finally joins everything into one circuit
+        euclidean_mst(input).last()
+            .map_or(0, |link| mult_x(input, link))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::fs;
+    use adventofcode2025::aocutils::spatial::{merge_sequence, rank_merges};
+    use super::*;
+
+    // The part 1 example connects 10 pairs rather than 1000, see test_calculate_circuits
+    adventofcode2025::example_tests!(Day8, part2);
+
+    #[test]
+    fn test_dist() {
+        let pos1 = Position::new(162, 817, 812);
+        let pos2 = Position::new(425, 690, 689);
+        assert_eq!(pos1.dist_sq(&pos2), 100427);
+        assert_eq!(Link::new((0, 19), &pos1, &pos2).dist_sq, 100427);
+    }
+
+    #[test]
+    fn test_link_order() {
+        let origin = Position::new(0, 0, 0);
+        let near = Link::new((0, 1), &origin, &Position::new(100000, 0, 0));
+        let far = Link::new((0, 2), &origin, &Position::new(100000, 0, 1));
+        assert!(near < far);
+
+        let tied = Link::new((1, 2), &Position::new(0, 0, 1), &Position::new(100000, 0, 1));
+        assert_eq!(tied.dist_sq, near.dist_sq);
+        assert!(near < tied);
+        assert!(tied < far);
+    }
+
+    #[test]
+    fn test_shortest_distance() {
+        let calculator = Calculator::new(&Day8::parse(&fs::read_to_string("day8/testdata/input_part_1.txt").unwrap()).unwrap(), 1);
+
+        let pos1 = Position::new(162, 817, 812);
+        let pos2 = Position::new(425, 690, 689);
+        let link = Link::new((0, 19), &pos1, &pos2);
+        assert_eq!(calculator.links[0], link);
+    }
+
+    #[test]
+    fn test_calculate_circuits() {
+        let calculator = Calculator::new(&Day8::parse(&fs::read_to_string("day8/testdata/input_part_1.txt").unwrap()).unwrap(), 10);
+        let (circuits, _) = calculator.calculate_circuits();
+
+        let size0 = circuits[0];
+        let size1 = circuits[1];
+        let size2 = circuits[2];
+        let size = size0 * size1 * size2;
+        assert_eq!(size0, 5);
+        assert_eq!(size1, 4);
+        assert_eq!(size2, 2);
+        assert_eq!(size, 40);
+    }
+
+    #[test]
+    fn test_merge_sequence() {
+        let positions = Day8::parse(&fs::read_to_string("day8/testdata/input_part_1.txt").unwrap()).unwrap();
+        let (_, unify_point) = Calculator::new(&positions, usize::MAX).calculate_circuits();
+        let mut merges = merge_sequence(&positions);
+        assert_eq!(merges.len(), positions.len() - 1);
+        assert_eq!(mult_x(&positions, &merges.last().unwrap().edge), unify_point);
+
+        // The first link joins two single boxes, and 11 merges leave 9 circuits
+        assert_eq!(merges[0].sizes, (1, 1));
+        assert_eq!(merges[0].edge, Link::new((0, 19), &positions[0], &positions[19]));
+        assert_eq!(merges[10].components, 9);
+
+        // The 10 shortest links leave 11 circuits, as one of them joins boxes already connected
+        rank_merges(&positions, &mut merges);
+        let merged = merges.iter().take_while(|merge| merge.links <= Some(10)).count();
+        assert_eq!(merged, 9);
+        assert_eq!(positions.len() - merged, 11);
+
+        let last = merges.last().unwrap();
+        assert_eq!(last.sizes.0 + last.sizes.1, positions.len());
+        assert_eq!(last.components, 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day8::parse("162,817,812\n57,618\n").err().unwrap();
+        assert_eq!(err.to_string(), "<input>:2: expected 3 comma-separated integers, found \"57,618\"");
+
+        let err = Day8::parse("162,817,812\n57,6x8,57\n").err().unwrap();
+        assert_eq!(err.to_string(), "<input>:2:4: expected an integer, found \"6x8\"");
+    }
+
+}
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+use std::io;
+use adventofcode2025::aocutils::geom::Point2;
+use adventofcode2025::aocutils::{parse_at, ParseError, Solution};
+
+type Point = Point2<u32>;
+
+/// Area of the rectangle of tiles with opposite corners `p1` and `p2`.
+fn tile_area(p1: &Point, p2: &Point) -> u64 {
+    (p1.x.abs_diff(p2.x) + 1) as u64 * (p1.y.abs_diff(p2.y) + 1) as u64
+}
+
+pub struct Grid {
+    positions: Vec<Point>
+}
+
+impl Grid {
+    fn new(raw: &str) -> io::Result<Grid> {
+        let mut positions = Vec::new();
+        for (i, line) in raw.lines().enumerate() {
+            let (x, y) = line.split_once(',')
+                .ok_or_else(|| ParseError::new(i + 1, line, "expected 2 comma-separated integers"))?;
+            positions.push(Point::new(
+                parse_at(i + 1, line, x, "an integer")?,
+                parse_at(i + 1, line, y, "an integer")?,
+            ));
+        }
+
+        if positions.is_empty() {
+            return Err(ParseError::new(1, "", "expected at least one red tile").into());
+        }
+
+        positions.sort();
+
+        Ok(Grid { positions })
+    }
+
+    fn max_area(&self) -> u64 {
+        let mut max_area = 0;
+        for i in 0..self.positions.len()-1 {
+            for j in i+1..self.positions.len() {
+                let p1 = &self.positions[i];
+                let p2 = &self.positions[j];
+                let area = tile_area(p1, p2);
+                if area > max_area {
+                    max_area = area;
+                }
+            }
+        }
+        max_area
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> io::Result<Self::Input> {
+        Grid::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.max_area()
+    }
+
+    fn part2(_input: &Self::Input) -> impl Display {
+        "unsolved"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    adventofcode2025::example_tests!(Day9, part1);
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+use super::{InputSource, Part, ParseError};
+
+/// Name of the file, next to a day's inputs, that records their known-good answers.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Known-good answers per input file and part, read from lines of
+/// `<input file name> <part> <answer>`. Blank lines and `#` comments are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<(String, Part, String)>,
+}
+
+impl Answers {
+
+    pub fn parse(raw: &str) -> Result<Answers, ParseError> {
+        let mut entries = Vec::new();
+
+        for (i, line) in raw.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = trimmed.splitn(3, char::is_whitespace).collect();
+            if fields.len() != 3 {
+                return Err(ParseError::new(i + 1, line, "expected <input> <part> <answer>"));
+            }
+            let part = fields[1].parse::<Part>()
+                .map_err(|_| ParseError::at(i + 1, line, fields[1], "expected part 1 or 2"))?;
+
+            entries.push((fields[0].to_owned(), part, fields[2].trim().to_owned()));
+        }
+
+        Ok(Answers { entries })
+    }
+
+    /// Loads the answers file next to `source`. Stdin, and inputs without an
+    /// answers file, have no known answers.
+    pub fn load_for(source: &InputSource) -> io::Result<Answers> {
+        let Some(path) = Answers::path_for(source) else {
+            return Ok(Answers::default());
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(raw) => Answers::parse(&raw)
+                .map_err(|e| e.with_file(path.display().to_string()).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        }
+    }
+
+    fn path_for(source: &InputSource) -> Option<PathBuf> {
+        match source {
+            InputSource::Stdin => None,
+            InputSource::File(path) => Some(path.with_file_name(ANSWERS_FILE)),
+        }
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.entries.iter()
+            .find(|(name, p, _)| name == input && *p == part)
+            .map(|(_, _, answer)| answer.as_str())
+    }
+
+    /// Every recorded `(input file name, answer)` for `part`, in file order.
+    pub fn for_part(&self, part: Part) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter()
+            .filter(move |(_, p, _)| *p == part)
+            .map(|(name, _, answer)| (name.as_str(), answer.as_str()))
+    }
+
+    /// Compares `answer` for `part` of `source` against the recorded one.
+    pub fn check(&self, source: &InputSource, part: Part, answer: &str) -> Verdict {
+        let expected = match source {
+            InputSource::Stdin => None,
+            InputSource::File(path) => path.file_name()
+                .and_then(|name| self.get(&name.to_string_lossy(), part)),
+        };
+
+        match expected {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_owned() },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const RAW: &str = "# input part answer
+input.txt 1 525
+input.txt 2 333892124923577
+
+other.txt 1 hello world
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(RAW).unwrap();
+        assert_eq!(answers.get("input.txt", Part::One), Some("525"));
+        assert_eq!(answers.get("input.txt", Part::Two), Some("333892124923577"));
+        assert_eq!(answers.get("other.txt", Part::One), Some("hello world"));
+        assert_eq!(answers.get("other.txt", Part::Two), None);
+
+        let part1: Vec<(&str, &str)> = answers.for_part(Part::One).collect();
+        assert_eq!(part1, vec![("input.txt", "525"), ("other.txt", "hello world")]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Answers::parse("input.txt 3 12").unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1:11: expected part 1 or 2, found \"3\"");
+        assert!(Answers::parse("input.txt 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(RAW).unwrap();
+        let source = InputSource::File(PathBuf::from("day5/input.txt"));
+        assert_eq!(answers.check(&source, Part::One, "525"), Verdict::Pass);
+        assert_eq!(answers.check(&source, Part::One, "526"), Verdict::Fail { expected: "525".to_owned() });
+        assert_eq!(answers.check(&InputSource::Stdin, Part::One, "525"), Verdict::Unknown);
+
+        let other = InputSource::File(PathBuf::from("day5/other.txt"));
+        assert_eq!(answers.check(&other, Part::Two, "1"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_load_for() {
+        let source = InputSource::File(PathBuf::from("no/such/dir/input.txt"));
+        assert_eq!(Answers::load_for(&source).unwrap(), Answers::default());
+        assert_eq!(Answers::load_for(&InputSource::Stdin).unwrap(), Answers::default());
+    }
+}
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+
+/// Each limb holds this many decimal digits, so that parsing and printing stay simple.
+const LIMB_DIGITS: usize = 18;
+const LIMB: u64 = 10u64.pow(LIMB_DIGITS as u32);
+
+/// An arbitrary-precision unsigned integer, enough to add up numbers of any length.
+/// Stored as little-endian base 10^18 limbs with no leading zero limbs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Parses a non-empty string of ASCII decimal digits.
+    pub fn from_decimal(digits: &str) -> Option<Self> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let limbs = digits.as_bytes()
+            .rchunks(LIMB_DIGITS)
+            .map(|chunk| chunk.iter().fold(0, |acc, &b| acc * 10 + (b - b'0') as u64))
+            .collect();
+        Some(Self { limbs }.trimmed())
+    }
+
+    fn trimmed(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut n: u128) -> Self {
+        let mut limbs = Vec::new();
+        while n > 0 {
+            limbs.push((n % LIMB as u128) as u64);
+            n /= LIMB as u128;
+        }
+        Self { limbs }
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<BigUint> {
+        BigUint::from_decimal(s)
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, format!("invalid number: {}", s)))
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, other: &BigUint) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb + other.limbs.get(i).copied().unwrap_or(0) + carry;
+            *limb = sum % LIMB;
+            carry = sum / LIMB;
+            if carry == 0 && i >= other.limbs.len() {
+                break;
+            }
+        }
+        if carry > 0 {
+            self.limbs.push(carry);
+        }
+    }
+}
+
+impl AddAssign for BigUint {
+    fn add_assign(&mut self, other: BigUint) {
+        *self += &other;
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(mut self, other: BigUint) -> BigUint {
+        self += &other;
+        self
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |acc, n| acc + n)
+    }
+}
+
+impl<'a> Sum<&'a BigUint> for BigUint {
+    fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |mut acc, n| {
+            acc += n;
+            acc
+        })
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((most, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+
+        write!(f, "{}", most)?;
+        for limb in rest.iter().rev() {
+            write!(f, "{:0width$}", limb, width = LIMB_DIGITS)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        for digits in ["0", "7", "999999999999999999", "1000000000000000000", "123456789012345678901234567890123456789012"] {
+            assert_eq!(BigUint::from_decimal(digits).unwrap().to_string(), digits);
+        }
+        assert_eq!(BigUint::from_decimal("000120").unwrap().to_string(), "120");
+        assert_eq!(BigUint::from_decimal("0000000000000000000000").unwrap(), BigUint::zero());
+
+        assert!(BigUint::from_decimal("").is_none());
+        assert!(BigUint::from_decimal("12a").is_none());
+        assert_eq!("-1".parse::<BigUint>().unwrap_err().to_string(), "invalid number: -1");
+    }
+
+    #[test]
+    fn test_add() {
+        let max = BigUint::from(u128::MAX);
+        assert_eq!((max.clone() + max.clone()).to_string(), "680564733841876926926749214863536422910");
+        assert_eq!((max.clone() + BigUint::from(1)).to_string(), "340282366920938463463374607431768211456");
+
+        // A carry that ripples through every limb
+        let nines = BigUint::from_decimal(&"9".repeat(60)).unwrap();
+        assert_eq!((nines + BigUint::from(1)).to_string(), format!("1{}", "0".repeat(60)));
+
+        let values: Vec<BigUint> = (1..=100u128).map(BigUint::from).collect();
+        assert_eq!(values.iter().sum::<BigUint>(), BigUint::from(5050));
+        assert_eq!(values.into_iter().sum::<BigUint>().to_string(), "5050");
+    }
+
+    #[test]
+    fn test_ord() {
+        let small = BigUint::from(u64::MAX as u128);
+        let large = BigUint::from_decimal(&"1".repeat(40)).unwrap();
+        assert!(small < large);
+        assert!(BigUint::zero() < small);
+        assert_eq!(large.cmp(&large.clone()), Ordering::Equal);
+    }
+}
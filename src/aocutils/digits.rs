@@ -0,0 +1,295 @@
+use std::cmp::Reverse;
+
+/// Digits picked out of a line, in their original order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    digits: Vec<u8>,
+    indices: Vec<usize>,
+}
+
+impl Selection {
+
+    /// The chosen digits as a decimal string.
+    pub fn digits(&self) -> &str {
+        // Only ever built from ASCII digits
+        std::str::from_utf8(&self.digits).unwrap()
+    }
+
+    /// Positions in the line of each chosen digit.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// The number the digits spell, or `None` if it does not fit in a `u128`.
+    pub fn value(&self) -> Option<u128> {
+        self.digits.iter().try_fold(0u128, |acc, &d| acc.checked_mul(10)?.checked_add((d - b'0') as u128))
+    }
+
+    fn from_indices(line: &[u8], indices: Vec<usize>) -> Self {
+        Self { digits: indices.iter().map(|&i| line[i]).collect(), indices }
+    }
+}
+
+/// Picks the `k` ASCII digits of `line` that spell the highest number while keeping
+/// their order, in a single pass: a digit evicts the smaller digits before it for as
+/// long as enough digits remain to still make up `k`. Returns `None` if the line is
+/// shorter than `k`.
+pub fn select_highest(line: &[u8], k: usize) -> Option<Selection> {
+    if line.len() < k {
+        return None;
+    }
+    Some(Selection::from_indices(line, monotonic(line, 0, k, |kept, digit| kept < digit)))
+}
+
+/// Picks the `k` digits of `line` that spell the lowest number without a leading zero,
+/// or `None` if the line is shorter than `k` or no digit that could lead is non-zero.
+pub fn select_lowest(line: &[u8], k: usize) -> Option<Selection> {
+    if k == 0 || line.len() < k {
+        return None;
+    }
+
+    // The lead is the smallest non-zero digit that leaves room for the rest, taken
+    // as early as possible; after it, zeros are as welcome as any other digit
+    let lead = (0..=line.len() - k)
+        .filter(|&i| line[i] != b'0')
+        .min_by_key(|&i| line[i])?;
+    let mut indices = vec![lead];
+    indices.extend(monotonic(line, lead + 1, k - 1, |kept, digit| kept > digit));
+    Some(Selection::from_indices(line, indices))
+}
+
+/// Picks the `k` digits of `line` that spell the highest number divisible by `modulus`,
+/// or `None` if no choice of `k` digits is. Needs O(len × k × modulus) time and memory.
+pub fn select_highest_divisible(line: &[u8], k: usize, modulus: u64) -> Option<Selection> {
+    if modulus == 0 || line.len() < k {
+        return None;
+    }
+    let (len, m) = (line.len(), modulus as usize);
+    let digit = |i: usize| (line[i] - b'0') as usize;
+
+    // pow[j] is 10^j mod m
+    let mut pow = vec![1 % m; k + 1];
+    for j in 1..=k {
+        pow[j] = pow[j - 1] * 10 % m;
+    }
+
+    // Whether some j digits of line[i..] spell a number with remainder r
+    let at = |i: usize, j: usize, r: usize| (i * (k + 1) + j) * m + r;
+    let mut reachable = vec![false; (len + 1) * (k + 1) * m];
+    reachable[at(len, 0, 0)] = true;
+    for i in (0..len).rev() {
+        reachable[at(i, 0, 0)] = true;
+        for j in 1..=k.min(len - i) {
+            let lead = digit(i) * pow[j - 1] % m;
+            for r in 0..m {
+                // Either line[i] leads the j digits, or it is skipped
+                reachable[at(i, j, r)] = reachable[at(i + 1, j, r)] || reachable[at(i + 1, j - 1, (r + m - lead) % m)];
+            }
+        }
+    }
+    if !reachable[at(0, k, 0)] {
+        return None;
+    }
+
+    // Greedily take the highest digit, earliest first, that the rest can still complete
+    let mut indices = Vec::with_capacity(k);
+    let (mut from, mut prefix) = (0, 0);
+    for j in (1..=k).rev() {
+        let (i, next) = (from..=len - j)
+            .map(|i| (i, (prefix * 10 + digit(i)) % m))
+            .filter(|&(i, next)| reachable[at(i + 1, j - 1, (m - next * pow[j - 1] % m) % m)])
+            .max_by_key(|&(i, _)| (line[i], Reverse(i)))
+            .unwrap();
+        indices.push(i);
+        (from, prefix) = (i + 1, next);
+    }
+    Some(Selection::from_indices(line, indices))
+}
+
+/// Picks the `k` digits of `line` that spell the `n`th highest of the distinct numbers
+/// they can make, counting from zero, or `None` if there are no more than `n` of them.
+pub fn select_nth_highest(line: &[u8], k: usize, n: u128) -> Option<Selection> {
+    if line.len() < k {
+        return None;
+    }
+    let len = line.len();
+
+    // next[i][d] is the first position at or after i holding the digit d
+    let mut next = vec![[None; 10]; len + 1];
+    for i in (0..len).rev() {
+        next[i] = next[i + 1];
+        next[i][(line[i] - b'0') as usize] = Some(i);
+    }
+
+    // How many distinct j-digit numbers line[i..] can make. Counting each by the
+    // earliest place its first digit occurs avoids double counting. The counts
+    // saturate, which is harmless: a saturated count is always above the rank sought.
+    let mut distinct = vec![vec![0u128; k + 1]; len + 1];
+    for i in (0..=len).rev() {
+        distinct[i][0] = 1;
+        for j in 1..=k {
+            distinct[i][j] = next[i].iter()
+                .flatten()
+                .fold(0, |acc: u128, &p| acc.saturating_add(distinct[p + 1][j - 1]));
+        }
+    }
+    if n >= distinct[0][k] {
+        return None;
+    }
+
+    let mut indices = Vec::with_capacity(k);
+    let (mut from, mut rank) = (0, n);
+    for j in (1..=k).rev() {
+        for p in next[from].iter().rev().flatten().copied() {
+            let count = distinct[p + 1][j - 1];
+            if rank < count {
+                indices.push(p);
+                from = p + 1;
+                break;
+            }
+            rank -= count;
+        }
+    }
+    Some(Selection::from_indices(line, indices))
+}
+
+/// Which `k` digits to reduce a line to, dispatching to the `select_*` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Query {
+    Highest,
+    Lowest,
+    HighestDivisible(u64),
+    NthHighest(u128),
+}
+
+impl Query {
+
+    pub fn select(self, line: &[u8], k: usize) -> Option<Selection> {
+        match self {
+            Query::Highest => select_highest(line, k),
+            Query::Lowest => select_lowest(line, k),
+            Query::HighestDivisible(modulus) => select_highest_divisible(line, k, modulus),
+            Query::NthHighest(n) => select_nth_highest(line, k, n),
+        }
+    }
+}
+
+/// Keeps `k` of the digits in `line[from..]` by index, letting each digit evict the
+/// ones before it for which `evicts(kept, digit)` holds while there are drops to spare.
+fn monotonic(line: &[u8], from: usize, k: usize, evicts: impl Fn(u8, u8) -> bool) -> Vec<usize> {
+    let mut drops = (line.len() - from).saturating_sub(k);
+    let mut stack: Vec<usize> = Vec::with_capacity(line.len() - from);
+
+    for i in from..line.len() {
+        while drops > 0 && stack.last().is_some_and(|&top| evicts(line[top], line[i])) {
+            stack.pop();
+            drops -= 1;
+        }
+        stack.push(i);
+    }
+    stack.truncate(k);
+    stack
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::BTreeSet;
+    use crate::aocutils::xorshift::XorShift64;
+    use super::*;
+
+    #[test]
+    fn test_select_highest() {
+        let cases = [
+            ("987654321111111", ["98765432111111", "9876543211111", "987654321111"]),
+            ("811111111111119", ["81111111111119", "8111111111119", "811111111119"]),
+            ("234234234234278", ["34234234234278", "4234234234278", "434234234278"]),
+            ("818181911112111", ["88181911112111", "8881911112111", "888911112111"]),
+        ];
+        for (line, expected) in cases {
+            for (k, expected) in (12..=14).rev().zip(expected) {
+                assert_eq!(select_highest(line.as_bytes(), k).unwrap().digits(), expected);
+            }
+        }
+
+        let selection = select_highest(b"818181911112111", 12).unwrap();
+        assert_eq!(selection.indices(), &[0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+        assert_eq!(selection.value(), Some(888911112111));
+
+        assert_eq!(select_highest(b"12", 3), None);
+        assert_eq!(select_highest(b"12", 2).unwrap().digits(), "12");
+        assert_eq!(select_highest(b"12", 0).unwrap().digits(), "");
+    }
+
+    #[test]
+    fn test_select_highest_long() {
+        let line = "9".repeat(50);
+        let selection = select_highest(line.as_bytes(), 40).unwrap();
+        assert_eq!(selection.digits(), "9".repeat(40));
+        assert_eq!(selection.value(), None);
+        assert_eq!(select_highest(line.as_bytes(), 38).unwrap().value(), Some(10u128.pow(38) - 1));
+    }
+
+    /// Every distinct `k`-digit subsequence of a short line, highest first.
+    fn brute_force(line: &str, k: usize) -> Vec<String> {
+        let bytes = line.as_bytes();
+        let found: BTreeSet<String> = (0u32..1 << bytes.len())
+            .filter(|mask| mask.count_ones() as usize == k)
+            .map(|mask| (0..bytes.len()).filter(|i| mask & 1 << i != 0).map(|i| bytes[i] as char).collect())
+            .collect();
+        found.into_iter().rev().collect()
+    }
+
+    fn short_lines() -> Vec<String> {
+        let mut lines: Vec<String> = ["0", "7", "0000", "1000", "0102", "9090", "3333", "2024121"].map(String::from).into();
+        for (i, state) in XorShift64::new(0x2545f4914f6cdd1d).take(150).enumerate() {
+            // Few distinct digits, so that repeated subsequences are common
+            let alphabet = 2 + i % 9;
+            lines.push((0..1 + i % 10).map(|j| char::from(b'0' + (state >> (3 * j)) as u8 % alphabet as u8)).collect());
+        }
+        lines
+    }
+
+    #[test]
+    fn test_queries_match_brute_force() {
+        for line in short_lines() {
+            let bytes = line.as_bytes();
+            for k in 0..=line.len() + 1 {
+                let all = brute_force(&line, k);
+                let digits = |selection: Option<Selection>| selection.map(|s| s.digits().to_owned());
+
+                assert_eq!(digits(select_highest(bytes, k)), all.first().cloned(), "{} {}", line, k);
+                let lowest = all.iter().rev().find(|d| k > 0 && !d.starts_with('0')).cloned();
+                assert_eq!(digits(select_lowest(bytes, k)), lowest, "{} {}", line, k);
+
+                for modulus in [1, 2, 3, 4, 7, 12] {
+                    let divisible = all.iter().find(|d| d.parse::<u64>().unwrap_or(0) % modulus == 0).cloned();
+                    assert_eq!(digits(select_highest_divisible(bytes, k, modulus)), divisible, "{} {} {}", line, k, modulus);
+                }
+
+                for n in 0..=all.len() {
+                    assert_eq!(digits(select_nth_highest(bytes, k, n as u128)), all.get(n).cloned(), "{} {} {}", line, k, n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_queries() {
+        let line = b"818181911112111";
+        assert_eq!(Query::Highest.select(line, 12).unwrap().digits(), "888911112111");
+        assert_eq!(Query::NthHighest(0).select(line, 12), Query::Highest.select(line, 12));
+        assert_eq!(Query::Lowest.select(b"100200", 3).unwrap().digits(), "100");
+        assert_eq!(Query::Lowest.select(b"1012", 3).unwrap().indices(), &[0, 1, 2]);
+        assert_eq!(Query::Lowest.select(b"0012", 3), None);
+        assert_eq!(Query::HighestDivisible(7).select(b"123456", 3).unwrap().digits(), "245");
+        assert_eq!(Query::HighestDivisible(0).select(b"123456", 3), None);
+        assert_eq!(Query::NthHighest(3).select(b"1111", 2), None);
+
+        // Counts far beyond a u128 saturate without disturbing the ranks that fit
+        let long = "0123456789".repeat(30);
+        let ranked: Vec<Selection> = (0..3).map(|n| select_nth_highest(long.as_bytes(), 60, n).unwrap()).collect();
+        assert_eq!(Some(ranked[0].clone()), select_highest(long.as_bytes(), 60));
+        assert!(ranked[0].digits() > ranked[1].digits() && ranked[1].digits() > ranked[2].digits());
+    }
+}
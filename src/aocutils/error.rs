@@ -0,0 +1,133 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::str::FromStr;
+
+/// A malformed line of puzzle input, located by line and (when known) column.
+///
+/// Parsers only see the input text, so the file is filled in afterwards by
+/// whoever read it, see [`ParseError::attach_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+
+    /// Error for the whole of line `line` (1-based), whose contents are `text`.
+    pub fn new(line: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column: None,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for `token`, which must be a subslice of `text` so that its column can be derived.
+    pub fn at(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+        let column = text.char_indices()
+            .position(|(i, _)| i == offset)
+            .or((offset == text.len()).then(|| text.chars().count()));
+
+        Self {
+            file: None,
+            line,
+            column: column.map(|c| c + 1),
+            text: token.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Fills in `file` on `err` if it wraps a [`ParseError`], leaving any other error untouched.
+    pub fn attach_file(err: io::Error, file: impl Into<String>) -> io::Error {
+        if !err.get_ref().is_some_and(|inner| inner.is::<ParseError>()) {
+            return err;
+        }
+
+        let inner = err.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        inner.with_file(file).into()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file.as_deref().unwrap_or("<input>"), self.line)?;
+        if let Some(column) = self.column {
+            write!(f, ":{}", column)?;
+        }
+        write!(f, ": {}, found {:?}", self.message, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(err: ParseError) -> io::Error {
+        io::Error::new(ErrorKind::InvalidData, err)
+    }
+}
+
+/// Parses `token`, a subslice of line `line` (1-based) with contents `text`,
+/// reporting `expected` as what should have been there on failure.
+pub fn parse_at<T: FromStr>(line: usize, text: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token.parse()
+        .map_err(|_| ParseError::at(line, text, token, format!("expected {}", expected)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(42, "1,2", "expected 3 comma-separated integers")
+            .with_file("day8/input.txt");
+        assert_eq!(err.to_string(), "day8/input.txt:42: expected 3 comma-separated integers, found \"1,2\"");
+    }
+
+    #[test]
+    fn test_at_column() {
+        let line = "162,8x7,812";
+        let token = line.split(',').nth(1).unwrap();
+        let err = ParseError::at(3, line, token, "expected an integer");
+        assert_eq!(err.column, Some(5));
+        assert_eq!(err.to_string(), "<input>:3:5: expected an integer, found \"8x7\"");
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let line = "3-x";
+        assert_eq!(parse_at::<u64>(1, line, &line[..1], "an integer"), Ok(3));
+        let err = parse_at::<u64>(1, line, &line[2..], "an integer").unwrap_err();
+        assert_eq!(err.column, Some(3));
+    }
+
+    #[test]
+    fn test_attach_file() {
+        let err: io::Error = ParseError::new(1, "", "expected a value").into();
+        let err = ParseError::attach_file(err, "day1/input.txt");
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "day1/input.txt:1: expected a value, found \"\"");
+
+        let other = io::Error::new(ErrorKind::NotFound, "missing");
+        assert_eq!(ParseError::attach_file(other, "day1/input.txt").to_string(), "missing");
+    }
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use super::{Answers, InputSource, Part, Solution, ANSWERS_FILE};
+
+/// Directory holding the worked examples for `day`, each listed with its
+/// expected answers in the directory's `answers.txt`.
+pub fn examples_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(format!("day{}", day))
+        .join("testdata")
+}
+
+/// Solves `part` of every example recorded for `S` and panics listing each
+/// one whose answer differs, or if no example is recorded for that part.
+pub fn check_examples<S: Solution>(part: Part) {
+    let dir = examples_dir(S::DAY);
+    let answers = Answers::load_for(&InputSource::File(dir.join(ANSWERS_FILE)))
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for (name, expected) in answers.for_part(part) {
+        let path = dir.join(name);
+        let raw = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let input = S::parse(&raw)
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+        let answer = match part {
+            Part::One => S::part1(&input).to_string(),
+            Part::Two => S::part2(&input).to_string(),
+        };
+        if answer != expected {
+            failures.push(format!("{}: expected {}, got {}", path.display(), expected, answer));
+        }
+        checked += 1;
+    }
+
+    assert!(checked > 0, "no examples recorded for day {} part {} in {}", S::DAY, part, dir.display());
+    assert!(failures.is_empty(), "day {} part {}:\n{}", S::DAY, part, failures.join("\n"));
+}
+
+/// Generates `example_part1` and `example_part2` tests that run [`check_examples`]
+/// for a [`Solution`]. Name the parts explicitly to only generate some of them,
+/// e.g. `example_tests!(Day8, part2)`.
+#[macro_export]
+macro_rules! example_tests {
+    (@part $solution:ty, part1) => {
+        #[test]
+        fn example_part1() {
+            $crate::aocutils::check_examples::<$solution>($crate::aocutils::Part::One);
+        }
+    };
+    (@part $solution:ty, part2) => {
+        #[test]
+        fn example_part2() {
+            $crate::aocutils::check_examples::<$solution>($crate::aocutils::Part::Two);
+        }
+    };
+    ($solution:ty, $($part:ident),+) => {
+        $( $crate::example_tests!(@part $solution, $part); )+
+    };
+    ($solution:ty) => {
+        $crate::example_tests!($solution, part1, part2);
+    };
+}
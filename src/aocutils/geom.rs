@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// An integer type usable as a point coordinate. Only implemented for types up
+/// to 32 bits wide, so that squared distances always fit in a `u128`.
+pub trait Coord: Copy + Ord + fmt::Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// `|self - other|`, widened so that it cannot overflow.
+    fn abs_diff_wide(self, other: Self) -> u128;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                fn abs_diff_wide(self, other: Self) -> u128 {
+                    self.abs_diff(other) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, u8, u16, u32);
+
+/// A point on a 2D integer grid. Ordered row-major: by `y`, then by `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point in 3D integer space. Ordered row-major: by `z`, then `y`, then `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point2<T> {
+
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u128 {
+        self.x.abs_diff_wide(other.x) + self.y.abs_diff_wide(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> u128 {
+        self.x.abs_diff_wide(other.x).max(self.y.abs_diff_wide(other.y))
+    }
+
+    /// Exact squared Euclidean distance.
+    pub fn dist_sq(&self, other: &Self) -> u128 {
+        self.x.abs_diff_wide(other.x).pow(2) + self.y.abs_diff_wide(other.y).pow(2)
+    }
+
+    pub fn cmp_row_major(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+
+    pub fn cmp_column_major(&self, other: &Self) -> Ordering {
+        (self.x, self.y).cmp(&(other.x, other.y))
+    }
+}
+
+impl<T: Coord> Point3<T> {
+
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u128 {
+        self.x.abs_diff_wide(other.x) + self.y.abs_diff_wide(other.y) + self.z.abs_diff_wide(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> u128 {
+        self.x.abs_diff_wide(other.x)
+            .max(self.y.abs_diff_wide(other.y))
+            .max(self.z.abs_diff_wide(other.z))
+    }
+
+    /// Exact squared Euclidean distance.
+    pub fn dist_sq(&self, other: &Self) -> u128 {
+        self.x.abs_diff_wide(other.x).pow(2)
+            + self.y.abs_diff_wide(other.y).pow(2)
+            + self.z.abs_diff_wide(other.z).pow(2)
+    }
+
+    pub fn cmp_row_major(&self, other: &Self) -> Ordering {
+        (self.z, self.y, self.x).cmp(&(other.z, other.y, other.x))
+    }
+
+    pub fn cmp_column_major(&self, other: &Self) -> Ordering {
+        (self.x, self.y, self.z).cmp(&(other.x, other.y, other.z))
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Coord> Ord for $point<T> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.cmp_row_major(other)
+            }
+        }
+
+        impl<T: Coord> PartialOrd for $point<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                Self { $($field: self.$field * scalar),+ }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(3i32, -4);
+        let b = Point2::new(1i32, 2);
+        assert_eq!(a + b, Point2::new(4, -2));
+        assert_eq!(a - b, Point2::new(2, -6));
+        assert_eq!(a * 3, Point2::new(9, -12));
+        assert_eq!(-a, Point2::new(-3, 4));
+
+        let mut c = Point3::new(1u32, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(2, 0, 1);
+        assert_eq!(c, Point3::new(0, 3, 3));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let a = Point3::new(162i32, 817, 812);
+        let b = Point3::new(425i32, 690, 689);
+        assert_eq!(a.manhattan(&b), 263 + 127 + 123);
+        assert_eq!(a.chebyshev(&b), 263);
+        assert_eq!(a.dist_sq(&b), 263 * 263 + 127 * 127 + 123 * 123);
+
+        let far = Point2::new(i32::MIN, i32::MIN);
+        let near = Point2::new(i32::MAX, i32::MAX);
+        assert_eq!(far.dist_sq(&near), 2 * (u32::MAX as u128).pow(2));
+
+        let far = Point3::new(0u32, 0, 0);
+        let near = Point3::new(u32::MAX, u32::MAX, u32::MAX);
+        assert_eq!(far.dist_sq(&near), 3 * (u32::MAX as u128).pow(2));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut points = vec![Point2::new(2u32, 1), Point2::new(1, 2), Point2::new(1, 1), Point2::new(2, 1)];
+        points.sort();
+        assert_eq!(points, vec![Point2::new(1, 1), Point2::new(2, 1), Point2::new(2, 1), Point2::new(1, 2)]);
+        assert_eq!(Point2::new(2u32, 1).cmp(&Point2::new(2, 1)), Ordering::Equal);
+
+        points.sort_by(Point2::cmp_column_major);
+        assert_eq!(points, vec![Point2::new(1, 1), Point2::new(1, 2), Point2::new(2, 1), Point2::new(2, 1)]);
+
+        assert!(Point3::new(9i32, 9, 0) < Point3::new(0, 0, 1));
+        assert_eq!(Point3::new(9i32, 9, 0).cmp_column_major(&Point3::new(0, 0, 1)), Ordering::Greater);
+    }
+}
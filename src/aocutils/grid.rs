@@ -0,0 +1,338 @@
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use super::ParseError;
+
+/// Offsets to the 4 orthogonal neighbors of a cell, as `(row, col)` deltas.
+pub const DIRS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets to all 8 neighbors of a cell, as `(row, col)` deltas.
+pub const DIRS8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1),           (0, 1),
+    (1, -1),  (1, 0),  (1, 1)
+];
+
+/// Which cells around a position count as its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// The 4 orthogonal neighbors.
+    VonNeumann,
+    /// The 8 orthogonal and diagonal neighbors.
+    Moore,
+    /// Every cell within Euclidean distance `r`.
+    Radius(usize),
+}
+
+impl Neighborhood {
+
+    /// Offsets to every neighbor, as `(row, col)` deltas in row-major order.
+    pub fn offsets(self) -> Vec<(isize, isize)> {
+        match self {
+            Neighborhood::VonNeumann => DIRS4.to_vec(),
+            Neighborhood::Moore => DIRS8.to_vec(),
+            Neighborhood::Radius(r) => {
+                let r = r as isize;
+                (-r..=r)
+                    .flat_map(|dr| (-r..=r).map(move |dc| (dr, dc)))
+                    .filter(|&(dr, dc)| (dr, dc) != (0, 0) && dr * dr + dc * dc <= r * r)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Parses `vn` (or `von-neumann`), `moore`, or `r` followed by a radius, as in `r2`.
+impl FromStr for Neighborhood {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Neighborhood> {
+        match s {
+            "vn" | "von-neumann" => Ok(Neighborhood::VonNeumann),
+            "moore" => Ok(Neighborhood::Moore),
+            _ => s.strip_prefix('r')
+                .and_then(|r| r.parse().ok())
+                .map(Neighborhood::Radius)
+                .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, format!("invalid neighborhood: {}", s))),
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row-major in a single `Vec`,
+/// addressed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from row-major `cells`, or `None` if there are not exactly `width * height`.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height).then_some(Self { width, height, cells })
+    }
+
+    /// Parses one row per line, converting each character with `cell`. A character
+    /// it rejects, or a row of a different width to the first, is a [`ParseError`]
+    /// reporting `expected`.
+    pub fn parse_with(raw: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (i, line) in raw.lines().enumerate() {
+            let start = cells.len();
+            for (pos, c) in line.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::at(i + 1, line, &line[pos..pos + c.len_utf8()], expected))?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(i + 1, line, format!("expected {} columns", width)));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.in_bounds(row, col).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        match self.in_bounds(row, col) {
+            true => Some(&mut self.cells[row * self.width + col]),
+            false => None,
+        }
+    }
+
+    /// The position `(row, col)` moved by `delta`, if it is still on the grid.
+    pub fn offset(&self, (row, col): (usize, usize), (dr, dc): (isize, isize)) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+        self.in_bounds(row, col).then_some((row, col))
+    }
+
+    /// The position `(row, col)` moved by `delta`, wrapping around the edges as on a torus.
+    pub fn offset_wrapping(&self, (row, col): (usize, usize), (dr, dc): (isize, isize)) -> (usize, usize) {
+        let row = (row as isize + dr).rem_euclid(self.height as isize);
+        let col = (col as isize + dc).rem_euclid(self.width as isize);
+        (row as usize, col as usize)
+    }
+
+    /// Positions of the orthogonal neighbors of `(row, col)` that lie on the grid.
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRS4.into_iter().filter_map(move |delta| self.offset((row, col), delta))
+    }
+
+    /// Positions of the orthogonal and diagonal neighbors of `(row, col)` that lie on the grid.
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRS8.into_iter().filter_map(move |delta| self.offset((row, col), delta))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "(0, {}) is outside a {}x{} grid", col, self.height, self.width);
+        self.cells[col..].iter().step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every position on the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, in row-major order, equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    /// Positions of every cell equal to `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+}
+
+impl Grid<char> {
+
+    /// Parses one row of characters per line.
+    pub fn parse(raw: &str) -> Result<Self, ParseError> {
+        Self::parse_with(raw, "expected a character", Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside a {}x{} grid", row, col, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside a {}x{} grid", row, col, height, width))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const RAW: &str = "ab.\n.#c\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(RAW).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.to_string(), RAW);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("abc\nab\n").unwrap_err();
+        assert_eq!(err.to_string(), "<input>:2: expected 3 columns, found \"ab\"");
+
+        let err = Grid::parse_with("..\n.x", "expected . or #", |c| (c == '.' || c == '#').then_some(c)).unwrap_err();
+        assert_eq!(err.to_string(), "<input>:2:2: expected . or #, found \"x\"");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8(0, 0).collect::<Vec<_>>(), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.neighbors8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        assert_eq!(Neighborhood::VonNeumann.offsets(), DIRS4);
+        assert_eq!(Neighborhood::Moore.offsets(), DIRS8);
+        assert_eq!(Neighborhood::Radius(1).offsets(), DIRS4);
+        assert_eq!(Neighborhood::Radius(2).offsets().len(), 12);
+        assert_eq!(Neighborhood::Radius(3).offsets().len(), 28);
+        assert!(Neighborhood::Radius(0).offsets().is_empty());
+
+        assert_eq!("vn".parse::<Neighborhood>().unwrap(), Neighborhood::VonNeumann);
+        assert_eq!("moore".parse::<Neighborhood>().unwrap(), Neighborhood::Moore);
+        assert_eq!("r12".parse::<Neighborhood>().unwrap(), Neighborhood::Radius(12));
+        for bad in ["", "r", "r-1", "R2", "hex"] {
+            assert_eq!(bad.parse::<Neighborhood>().unwrap_err().to_string(), format!("invalid neighborhood: {}", bad));
+        }
+    }
+
+    #[test]
+    fn test_offset_wrapping() {
+        let grid = Grid::new(4, 3, 0);
+        assert_eq!(grid.offset_wrapping((0, 0), (-1, -1)), (2, 3));
+        assert_eq!(grid.offset_wrapping((2, 3), (1, 1)), (0, 0));
+        assert_eq!(grid.offset_wrapping((1, 1), (7, -9)), (2, 0));
+        assert_eq!(grid.offset((0, 0), (-1, -1)), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse(RAW).unwrap();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["ab.", ".#c"]);
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, vec!["a.", "b#", ".c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 3) is outside a 2x3 grid")]
+    fn test_column_out_of_bounds() {
+        Grid::parse(RAW).unwrap().column(3).count();
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = Grid::parse(RAW).unwrap();
+        assert_eq!(grid.find(&'#'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.find_all(&'.').collect::<Vec<_>>(), vec![(0, 2), (1, 0)]);
+
+        grid[(0, 0)] = '.';
+        assert_eq!(grid.find_all(&'.').count(), 3);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        assert!(Grid::from_vec(2, 2, vec![1, 2, 3]).is_none());
+        let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(grid.row(1), &[3, 4]);
+    }
+}
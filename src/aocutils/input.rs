@@ -0,0 +1,107 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory laid out like this repository
+/// (`dayN/input.txt`), used in place of the bundled inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+
+    /// Resolves the input for `day` the same way for every day:
+    /// an explicit path argument wins (`-` meaning stdin), then `$AOC_INPUT_DIR/dayN/input.txt`,
+    /// then the bundled `dayN/input.txt` next to this crate's manifest.
+    pub fn resolve(day: u8, arg: Option<&str>) -> InputSource {
+        let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        Self::resolve_with(day, arg, input_dir.as_deref())
+    }
+
+    fn resolve_with(day: u8, arg: Option<&str>, input_dir: Option<&Path>) -> InputSource {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => {
+                let base = input_dir.unwrap_or(Path::new(env!("CARGO_MANIFEST_DIR")));
+                InputSource::File(base.join(format!("day{}", day)).join("input.txt"))
+            }
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw).map(|_| raw)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+
+        result.map_err(|e| io::Error::new(e.kind(), format!("{}: {}", self, e)))
+    }
+
+    /// Opens the input for reading line by line, for days that stream it rather than read it whole.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", self, e))),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_resolve_explicit_path() {
+        let source = InputSource::resolve_with(5, Some("other/input.txt"), Some(Path::new("/inputs")));
+        assert_eq!(source, InputSource::File(PathBuf::from("other/input.txt")));
+    }
+
+    #[test]
+    fn test_resolve_stdin() {
+        assert_eq!(InputSource::resolve_with(5, Some("-"), None), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_resolve_input_dir() {
+        let source = InputSource::resolve_with(5, None, Some(Path::new("/inputs")));
+        assert_eq!(source, InputSource::File(PathBuf::from("/inputs/day5/input.txt")));
+    }
+
+    #[test]
+    fn test_resolve_default() {
+        let source = InputSource::resolve_with(5, None, None);
+        let expected = Path::new(env!("CARGO_MANIFEST_DIR")).join("day5/input.txt");
+        assert_eq!(source, InputSource::File(expected));
+    }
+
+    #[test]
+    fn test_open_missing_file() {
+        let source = InputSource::File(PathBuf::from("no/such/dir/input.txt"));
+        let err = source.open().err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().starts_with("no/such/dir/input.txt: "));
+    }
+}
@@ -0,0 +1,281 @@
+use std::fmt::Debug;
+
+/// An integer type whose values can be counted and stepped through one by one.
+pub trait Discrete: Copy + Ord + Debug {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn succ(self) -> Option<Self>;
+
+    fn pred(self) -> Option<Self>;
+
+    /// Number of values in `start..=end`, which must not be empty.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    end.abs_diff(start) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Whether a range ending at `end` stops short of `start` with a gap between them.
+fn before<T: Discrete>(end: T, start: T) -> bool {
+    end.succ().is_some_and(|next| next < start)
+}
+
+/// A set of integers stored as sorted, disjoint, inclusive `(start, end)` ranges.
+/// Overlapping and adjacent ranges are always merged, so `3-5` and `6-8` become `3-8`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a set from ranges already sorted by start, merging as it goes.
+    fn from_sorted(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut merged: Vec<(T, T)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if !before(last.1, start) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    /// Adds `start..=end`. An empty range, with `start > end`, adds nothing.
+    pub fn insert(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        let lo = self.ranges.partition_point(|&(_, e)| before(e, start));
+        let hi = self.ranges.partition_point(|&(s, _)| !before(end, s));
+
+        let mut merged = (start, end);
+        if lo < hi {
+            merged.0 = merged.0.min(self.ranges[lo].0);
+            merged.1 = merged.1.max(self.ranges[hi - 1].1);
+        }
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Removes `start..=end`, splitting any range it falls inside.
+    pub fn remove(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        let lo = self.ranges.partition_point(|&(_, e)| e < start);
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end);
+        if lo == hi {
+            return;
+        }
+
+        let mut pieces = Vec::with_capacity(2);
+        if let Some(left_end) = start.pred().filter(|&p| self.ranges[lo].0 <= p) {
+            pieces.push((self.ranges[lo].0, left_end));
+        }
+        if let Some(right_start) = end.succ().filter(|&s| s <= self.ranges[hi - 1].1) {
+            pieces.push((right_start, self.ranges[hi - 1].1));
+        }
+        self.ranges.splice(lo..hi, pieces);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+            match x <= y {
+                true => ranges.push(*a.next().unwrap()),
+                false => ranges.push(*b.next().unwrap()),
+            }
+        }
+        ranges.extend(a.chain(b));
+        Self::from_sorted(ranges)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            match a.1 < b.1 {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN, T::MAX))
+    }
+
+    /// Every value in `lo..=hi` that is not in the set.
+    pub fn complement(&self, lo: T, hi: T) -> Self {
+        let mut ranges = Vec::new();
+        let mut next = Some(lo);
+        for &(start, end) in &self.ranges {
+            let Some(from) = next.filter(|&from| from <= hi) else {
+                break;
+            };
+            if from < start {
+                ranges.push((from, start.pred().unwrap().min(hi)));
+            }
+            next = next.max(end.succ()).filter(|_| end < T::MAX);
+        }
+        if let Some(from) = next.filter(|&from| from <= hi) {
+            ranges.push((from, hi));
+        }
+        Self { ranges }
+    }
+
+    /// Number of values in the set.
+    pub fn total_len(&self) -> u128 {
+        self.ranges.iter().map(|&(start, end)| T::count(start, end)).sum()
+    }
+
+    /// Whether the set holds no values at all.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges making up the set, in increasing order.
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        self.ranges.iter().copied()
+    }
+}
+
+impl<T: Discrete> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut ranges: Vec<(T, T)> = iter.into_iter()
+            .filter(|&(start, end)| start <= end)
+            .collect();
+        ranges.sort_unstable();
+        Self::from_sorted(ranges)
+    }
+}
+
+impl<T: Discrete> Extend<(T, T)> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        *self = self.union(&iter.into_iter().collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(set(&[(10, 14), (3, 5), (16, 20), (12, 18)]).ranges(), &[(3, 5), (10, 20)]);
+        assert_eq!(set(&[(3, 5), (6, 8)]).ranges(), &[(3, 8)]);
+        assert_eq!(set(&[(3, 5), (7, 8), (5, 2)]).ranges(), &[(3, 5), (7, 8)]);
+        assert!(set(&[]).is_empty());
+        assert_eq!(set(&[(i32::MAX - 1, i32::MAX), (i32::MIN, 0)]).total_len(), (1 << 31) + 3);
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut s = IntervalSet::new();
+        s.insert(10, 20);
+        s.insert(1, 3);
+        s.insert(4, 4);
+        s.insert(22, 25);
+        assert_eq!(s.ranges(), &[(1, 4), (10, 20), (22, 25)]);
+        s.insert(21, 21);
+        assert_eq!(s.ranges(), &[(1, 4), (10, 25)]);
+
+        s.remove(12, 14);
+        assert_eq!(s.ranges(), &[(1, 4), (10, 11), (15, 25)]);
+        s.remove(0, 10);
+        assert_eq!(s.ranges(), &[(11, 11), (15, 25)]);
+        s.remove(20, 30);
+        s.remove(7, 3);
+        assert_eq!(s.ranges(), &[(11, 11), (15, 19)]);
+        assert_eq!(s.total_len(), 6);
+
+        let mut full = IntervalSet::new();
+        full.insert(u8::MIN, u8::MAX);
+        full.remove(0, 0);
+        full.remove(255, 255);
+        assert_eq!(full.ranges(), &[(1, 254)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(3, 5), (10, 20)]);
+        let inside: Vec<i32> = (0..25).filter(|&v| s.contains(v)).collect();
+        assert_eq!(inside, [3, 4, 5].into_iter().chain(10..=20).collect::<Vec<_>>());
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 20)]);
+        assert_eq!(a.union(&b).ranges(), &[(1, 15), (20, 20)]);
+        assert_eq!(a.intersection(&b).ranges(), &[(4, 5), (10, 11)]);
+        assert_eq!(a.difference(&b).ranges(), &[(1, 3), (12, 15)]);
+        assert_eq!(b.difference(&a).ranges(), &[(6, 9), (20, 20)]);
+        assert_eq!(a.complement(0, 12).ranges(), &[(0, 0), (6, 9)]);
+        assert_eq!(a.complement(2, 4).ranges(), &[] as &[(i32, i32)]);
+        assert_eq!(set(&[(i32::MIN, 0)]).complement(i32::MIN, i32::MAX).ranges(), &[(1, i32::MAX)]);
+        assert_eq!(set(&[(0, i32::MAX)]).complement(i32::MIN, i32::MAX).ranges(), &[(i32::MIN, -1)]);
+
+        // Checked against plain membership over a small universe
+        for v in -2..25 {
+            assert_eq!(a.union(&b).contains(v), a.contains(v) || b.contains(v));
+            assert_eq!(a.intersection(&b).contains(v), a.contains(v) && b.contains(v));
+            assert_eq!(a.difference(&b).contains(v), a.contains(v) && !b.contains(v));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::Write as _;
+use std::io::{self, ErrorKind, Write};
+use std::str::FromStr;
+use super::Report;
+
+/// How the runner writes out a [`Report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Human readable lines, one per part.
+    #[default]
+    Text,
+    /// One JSON object per line and part: `{day, part, answer, parse_ns, solve_ns}`.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Format> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(io::Error::new(ErrorKind::InvalidInput, format!("invalid format: {}", s))),
+        }
+    }
+}
+
+impl Format {
+
+    pub fn write(&self, out: &mut impl Write, report: &Report) -> io::Result<()> {
+        match self {
+            Format::Text => {
+                writeln!(out, "Day {} (parse: {:?})", report.day, report.parse)?;
+                for answer in &report.answers {
+                    writeln!(out, "  Part {}: {} ({:?})", answer.part, answer.answer, answer.elapsed)?;
+                }
+            }
+            Format::Json => {
+                for answer in &report.answers {
+                    writeln!(out, "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+                        report.day,
+                        answer.part,
+                        json_string(&answer.answer),
+                        report.parse.as_nanos(),
+                        answer.elapsed.as_nanos())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Quotes and escapes `s` as a JSON string literal.
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+    use super::*;
+    use crate::aocutils::{Answer, Part};
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("525"), "\"525\"");
+        assert_eq!(json_string("a\"b\\c\n\u{1}"), "\"a\\\"b\\\\c\\n\\u0001\"");
+    }
+
+    #[test]
+    fn test_write_json() {
+        let report = Report {
+            day: 5,
+            parse: Duration::from_nanos(1200),
+            answers: vec![
+                Answer { part: Part::One, answer: "3".to_owned(), elapsed: Duration::from_nanos(40) },
+                Answer { part: Part::Two, answer: "14".to_owned(), elapsed: Duration::from_nanos(7) },
+            ],
+        };
+
+        let mut out = Vec::new();
+        Format::Json.write(&mut out, &report).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), concat!(
+            "{\"day\":5,\"part\":1,\"answer\":\"3\",\"parse_ns\":1200,\"solve_ns\":40}\n",
+            "{\"day\":5,\"part\":2,\"answer\":\"14\",\"parse_ns\":1200,\"solve_ns\":7}\n",
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+/// Which numbers count as repeated, by how many times their shortest block of digits repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// Splits into exactly `k` equal blocks, so `Exactly(2)` includes 1111 as 11 11.
+    Exactly(u32),
+    /// Its shortest block repeats at least `k` times.
+    AtLeast(u32),
+    /// Its shortest block repeats a prime number of times.
+    Prime,
+}
+
+impl Rule {
+
+    /// Whether a number made of its shortest block repeated `reps` times matches.
+    pub fn accepts(self, reps: u32) -> bool {
+        match self {
+            Rule::Exactly(k) => reps.is_multiple_of(k),
+            Rule::AtLeast(k) => reps >= k,
+            Rule::Prime => reps >= 2 && (2..reps).take_while(|d| d * d <= reps).all(|d| !reps.is_multiple_of(d)),
+        }
+    }
+
+    pub fn matches(self, id: u128, base: u32) -> bool {
+        self.accepts(classify(id, base).reps)
+    }
+}
+
+/// How the digits of an ID break down into copies of its shortest repeating block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repetition {
+    pub block: u128,
+    /// Digits in `block`, which is also the ID's minimal period.
+    pub period: u32,
+    pub reps: u32,
+}
+
+/// Number of digits in `n` written in `base`.
+fn digits(n: u128, base: u32) -> u32 {
+    n.checked_ilog(base as u128).map_or(1, |log| log + 1)
+}
+
+/// Finds the shortest block whose repeats spell out `id` in `base`. An ID with no
+/// shorter repeating block is its own block, repeated once.
+pub fn classify(id: u128, base: u32) -> Repetition {
+    let len = digits(id, base);
+    for period in (1..len).filter(|&period| len.is_multiple_of(period)) {
+        let block = id / pow(base, len - period).unwrap();
+        if repeater(len, period, base).and_then(|r| r.checked_mul(block)) == Some(id) {
+            return Repetition { block, period, reps: len / period };
+        }
+    }
+    Repetition { block: id, period: len, reps: 1 }
+}
+
+/// `base^exp`, or `None` if it does not fit in a `u128`.
+fn pow(base: u32, exp: u32) -> Option<u128> {
+    (base as u128).checked_pow(exp)
+}
+
+/// The multiplier that repeats a `block`-digit number to fill `len` digits in `base`,
+/// `1 + base^block + base^2block + ...`, or `None` if it does not fit in a `u128`.
+fn repeater(len: u32, block: u32, base: u32) -> Option<u128> {
+    (0..len / block).try_fold(0u128, |acc, i| acc.checked_add(pow(base, i * block)?))
+}
+
+/// Sum of every `len`-digit number in `start..=end` that is a `block`-digit
+/// number repeated, with `block` dividing `len`.
+fn sum_periodic(start: u128, end: u128, base: u32, len: u32, block: u32) -> Option<u128> {
+    let lo = start.max(pow(base, len - 1)?);
+    let hi = end.min(pow(base, len).map_or(u128::MAX, |p| p - 1));
+    let Some(repeater) = repeater(len, block, base).filter(|_| lo <= hi) else {
+        return Some(0);
+    };
+
+    let first = lo.div_ceil(repeater).max(pow(base, block - 1)?);
+    let last = (hi / repeater).min(pow(base, block).map_or(u128::MAX, |p| p - 1));
+    if first > last {
+        return Some(0);
+    }
+
+    // first + last is even whenever the count is odd, so one of them halves exactly
+    let count = last - first + 1;
+    let blocks = match count % 2 {
+        0 => (count / 2).checked_mul(first.checked_add(last)?)?,
+        _ => count.checked_mul((first + last) / 2)?,
+    };
+    blocks.checked_mul(repeater)
+}
+
+/// The Möbius function, for the small arguments that digit counts produce.
+fn mobius(mut n: u32) -> i32 {
+    let mut result = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            result = -result;
+        }
+        p += 1;
+    }
+    if n > 1 { -result } else { result }
+}
+
+/// Sum of the numbers in `start..=end` whose digits in `base` are their shortest
+/// repeating block repeated `r` times, over every `r` that `accept` allows, so the
+/// same as summing the IDs whose [`classify`] repetitions `accept` takes. Works a
+/// digit length at a time and never visits the numbers themselves, so the size of
+/// the range does not matter. `None` if the sum does not fit in a `u128`.
+pub fn sum_repeated(start: u128, end: u128, base: u32, accept: impl Fn(u32) -> bool) -> Option<u128> {
+    let mut total: u128 = 0;
+    let max_len = digits(u128::MAX, base);
+
+    for len in 1..=max_len {
+        if pow(base, len - 1)? > end {
+            break;
+        }
+
+        for block in (1..=len).filter(|&block| len.is_multiple_of(block)) {
+            if !accept(len / block) {
+                continue;
+            }
+
+            // Numbers whose shortest block is exactly `block` digits long, by inclusion-exclusion
+            // over the numbers repeating any block length that divides it
+            let (mut added, mut removed): (u128, u128) = (0, 0);
+            for sub in (1..=block).filter(|&sub| block.is_multiple_of(sub)) {
+                let sum = sum_periodic(start, end, base, len, sub)?;
+                match mobius(block / sub) {
+                    1 => added = added.checked_add(sum)?,
+                    -1 => removed = removed.checked_add(sum)?,
+                    _ => {}
+                }
+            }
+            total = total.checked_add(added - removed)?;
+        }
+    }
+
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_sum_repeated_large_ranges() {
+        // 111111 repeats blocks of 1, 2 and 3 digits but is only counted once
+        assert_eq!(sum_repeated(111111, 111111, 10, |reps| reps >= 2), Some(111111));
+
+        // Every 20-digit number made of two equal 10-digit halves
+        let halves: u128 = (1_000_000_000 + 9_999_999_999) * 9_000_000_000 / 2;
+        let expected = halves * 10_000_000_001;
+        let (lo, hi) = (10u128.pow(19), 10u128.pow(20) - 1);
+        assert_eq!(sum_repeated(lo, hi, 10, |reps| reps.is_multiple_of(2)), Some(expected));
+
+        assert_eq!(sum_repeated(0, u64::MAX as u128, 10, |reps| reps >= 2).map(|sum| sum > 0), Some(true));
+        assert_eq!(sum_repeated(0, u128::MAX, 10, |reps| reps >= 2), None);
+        assert_eq!(sum_repeated(u128::MAX - 1, u128::MAX, 10, |reps| reps >= 2), Some(0));
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify(123123123, 10), Repetition { block: 123, period: 3, reps: 3 });
+        assert_eq!(classify(111111, 10), Repetition { block: 1, period: 1, reps: 6 });
+        assert_eq!(classify(121212, 10), Repetition { block: 12, period: 2, reps: 3 });
+        assert_eq!(classify(1231, 10), Repetition { block: 1231, period: 4, reps: 1 });
+        assert_eq!(classify(0, 10), Repetition { block: 0, period: 1, reps: 1 });
+        assert_eq!(classify(u128::MAX, 10).reps, 1);
+
+        assert_eq!(classify(0xabab, 16), Repetition { block: 0xab, period: 2, reps: 2 });
+        assert_eq!(classify(0xaaaa, 16), Repetition { block: 0xa, period: 1, reps: 4 });
+        assert_eq!(classify(0xabab, 10).reps, 1);
+        assert_eq!(classify(u128::MAX, 2), Repetition { block: 1, period: 1, reps: 128 });
+    }
+
+    #[test]
+    fn test_sum_repeated_other_rules_and_bases() {
+        let rules = [Rule::Exactly(3), Rule::AtLeast(3), Rule::Prime, Rule::Exactly(2)];
+        for base in [2, 10, 16] {
+            for (start, end) in [(1u128, 20_000), (0xfff0, 0x1_0100), (0xabab_0000, 0xabab_ffff)] {
+                for rule in rules {
+                    let expected: u128 = (start..=end).filter(|&id| rule.matches(id, base)).sum();
+                    assert_eq!(sum_repeated(start, end, base, |reps| rule.accepts(reps)), Some(expected), "{:?} base {}", rule, base);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        assert!(Rule::Exactly(3).matches(111111, 10));
+        assert!(!Rule::Exactly(3).matches(1212, 10));
+        assert!(Rule::AtLeast(3).matches(121212, 10));
+        assert!(!Rule::AtLeast(3).matches(1212, 10));
+        assert!(Rule::Prime.matches(111, 10));
+        assert!(Rule::Prime.matches(1212, 10));
+        assert!(!Rule::Prime.matches(111111, 10));
+        assert!(!Rule::Prime.matches(1234, 10));
+    }
+}
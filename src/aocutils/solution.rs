@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::io::{self, ErrorKind};
+use std::str::FromStr;
+use std::time::Duration;
+use super::{BenchConfig, InputSource, ParseError, RunTimer};
+
+/// A single day's puzzle, split into a parsing stage and one solving stage per part.
+///
+/// Parsing is fallible and happens once; both parts then borrow the parsed input,
+/// so the runner, the tests and the benchmarks can time each stage on its own.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// Names of the day-specific `--<name> <value>` settings this day accepts.
+    const SETTINGS: &'static [&'static str] = &[];
+
+    /// Names of the day-specific `--<name>` switches this day accepts, which take no value.
+    const FLAGS: &'static [&'static str] = &[];
+
+    /// Malformed input should be reported as a [`ParseError`] converted into an `io::Error`.
+    fn parse(input: &str) -> io::Result<Self::Input>;
+
+    /// Parses `input` under `settings`, which only ever name entries of [`Solution::SETTINGS`]
+    /// or [`Solution::FLAGS`].
+    /// Days without settings keep the default, which ignores them and calls [`Solution::parse`].
+    fn parse_with(input: &str, settings: &Settings) -> io::Result<Self::Input> {
+        let _ = settings;
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Every part matching `part`, where `None` selects both.
+    pub fn selected(part: Option<Part>) -> impl Iterator<Item = Part> {
+        Part::ALL.into_iter().filter(move |&p| part.is_none_or(|selected| selected == p))
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Part> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(io::Error::new(ErrorKind::InvalidInput, format!("invalid part: {}", s))),
+        }
+    }
+}
+
+/// Day-specific settings given on the command line as `--<name> <value>`, or just
+/// `--<name>` for a switch, kept as text until the day that declares them parses them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    values: BTreeMap<String, String>,
+}
+
+impl Settings {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// Whether `name` was given, as a switch or with a value.
+    pub fn is_set(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// A copy holding only the settings whose names `keep` accepts.
+    pub fn only(&self, keep: impl Fn(&str) -> bool) -> Settings {
+        let values = self.values.iter()
+            .filter(|(name, _)| keep(name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        Settings { values }
+    }
+
+    /// The value of `name` parsed as a `T`, or `None` if it was not given.
+    pub fn get<T: FromStr>(&self, name: &str) -> io::Result<Option<T>> {
+        self.values.get(name)
+            .map(|value| value.parse()
+                .map_err(|_| io::Error::new(ErrorKind::InvalidInput, format!("invalid value for --{}: {}", name, value))))
+            .transpose()
+    }
+}
+
+/// Parses `raw` with `S` and solves the requested part, or both when `part` is `None`.
+/// Each stage is timed as a span of `timer` nested under `dayN`.
+pub fn solve<S: Solution>(raw: &str, part: Option<Part>, settings: &Settings, timer: &RunTimer) -> io::Result<Report> {
+    let _day = timer.span(format!("day{}", S::DAY));
+
+    let span = timer.span("parse");
+    let input = S::parse_with(raw, settings)?;
+    let parse = span.close();
+
+    let answers = Part::selected(part)
+        .map(|p| {
+            let span = timer.span(format!("part{}", p));
+            let answer = match p {
+                Part::One => S::part1(&input).to_string(),
+                Part::Two => S::part2(&input).to_string(),
+            };
+            Answer { part: p, answer, elapsed: span.close() }
+        })
+        .collect();
+
+    Ok(Report { day: S::DAY, parse, answers })
+}
+
+/// Benchmarks parsing and the requested parts of `S` on `raw` with [`RunTimer::bench`],
+/// as spans of `timer` nested under `dayN`.
+pub fn bench<S: Solution>(raw: &str, part: Option<Part>, settings: &Settings, config: &BenchConfig, timer: &RunTimer) -> io::Result<()> {
+    let _day = timer.span(format!("day{}", S::DAY));
+
+    // Parse once up front so malformed input is reported rather than benchmarked
+    let input = S::parse_with(raw, settings)?;
+    timer.bench("parse", config, || S::parse_with(raw, settings));
+
+    for p in Part::selected(part) {
+        timer.bench(format!("part{}", p), config, || match p {
+            Part::One => S::part1(&input).to_string(),
+            Part::Two => S::part2(&input).to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Everything produced by one run of a day: its answers and how long each stage took.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+struct Entry {
+    settings: &'static [&'static str],
+    flags: &'static [&'static str],
+    solve: fn(&str, Option<Part>, &Settings, &RunTimer) -> io::Result<Report>,
+    bench: fn(&str, Option<Part>, &Settings, &BenchConfig, &RunTimer) -> io::Result<()>,
+}
+
+impl Entry {
+
+    fn accepts(&self, name: &str) -> bool {
+        self.settings.contains(&name) || self.flags.contains(&name)
+    }
+}
+
+/// Registry of every day's [`Solution`], keyed by day number.
+#[derive(Default)]
+pub struct Runner {
+    days: BTreeMap<u8, Entry>,
+}
+
+impl Runner {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution>(&mut self) -> &mut Self {
+        self.days.insert(S::DAY, Entry { settings: S::SETTINGS, flags: S::FLAGS, solve: solve::<S>, bench: bench::<S> });
+        self
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Whether any registered day accepts the setting `name`.
+    pub fn has_setting(&self, name: &str) -> bool {
+        self.days.values().any(|entry| entry.settings.contains(&name))
+    }
+
+    /// Whether any registered day accepts the switch `name`.
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.days.values().any(|entry| entry.flags.contains(&name))
+    }
+
+    /// Whether `day` is registered and accepts the setting or switch `name`.
+    pub fn accepts(&self, day: u8, name: &str) -> bool {
+        self.days.get(&day).is_some_and(|entry| entry.accepts(name))
+    }
+
+    fn entry(&self, day: u8) -> io::Result<&Entry> {
+        self.days.get(&day)
+            .ok_or_else(|| io::Error::new(ErrorKind::NotFound, format!("day {} is not registered", day)))
+    }
+
+    /// Solves `day` on `source`. Of `settings`, the day only sees those it declares,
+    /// so that one set of settings can be shared by every day being run.
+    pub fn run(&self, day: u8, part: Option<Part>, source: &InputSource, settings: &Settings, timer: &RunTimer) -> io::Result<Report> {
+        let entry = self.entry(day)?;
+        let input = source.read()?;
+
+        (entry.solve)(&input, part, &settings.only(|name| entry.accepts(name)), timer).map_err(|e| ParseError::attach_file(e, source.to_string()))
+    }
+
+    /// Benchmarks `day` on `source`, passing it settings as [`Runner::run`] does.
+    pub fn bench(&self, day: u8, part: Option<Part>, source: &InputSource, settings: &Settings, config: &BenchConfig, timer: &RunTimer) -> io::Result<()> {
+        let entry = self.entry(day)?;
+        let input = source.read()?;
+
+        (entry.bench)(&input, part, &settings.only(|name| entry.accepts(name)), config, timer).map_err(|e| ParseError::attach_file(e, source.to_string()))
+    }
+}
@@ -0,0 +1,370 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use super::dsu::DisjointSet;
+use super::geom::{Coord, Point3};
+
+/// A pair of points by index, with `ends.0 < ends.1`. Edges order by exact squared
+/// distance, then by their indices, so equal distances always resolve the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Edge {
+    pub dist_sq: u128,
+    pub ends: (usize, usize),
+}
+
+impl Edge {
+
+    pub fn new<T: Coord>(ends: (usize, usize), pos1: &Point3<T>, pos2: &Point3<T>) -> Self {
+        Self { dist_sq: pos1.dist_sq(pos2), ends }
+    }
+}
+
+fn axis<T: Coord>(point: &Point3<T>, axis: usize) -> T {
+    match axis % 3 {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+/// A static k-d tree over 3D points, stored as an implicit balanced tree of indices.
+#[derive(Debug, Clone)]
+pub struct KdTree<T> {
+    points: Vec<Point3<T>>,
+    order: Vec<usize>,
+}
+
+impl<T: Coord> KdTree<T> {
+
+    pub fn new(points: &[Point3<T>]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        Self::build(points, &mut order, 0);
+        Self { points: points.to_vec(), order }
+    }
+
+    fn build(points: &[Point3<T>], order: &mut [usize], depth: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by_key(mid, |&i| axis(&points[i], depth));
+        let (left, right) = order.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[Point3<T>] {
+        &self.points
+    }
+
+    /// The `k` points nearest `target` as `(squared distance, index)`, closest
+    /// first, with equal distances ordered by index.
+    pub fn nearest(&self, target: &Point3<T>, k: usize) -> Vec<(u128, usize)> {
+        self.nearest_where(target, k, |_| true)
+    }
+
+    /// As [`KdTree::nearest`], considering only the indices that `accept` allows.
+    pub fn nearest_where(&self, target: &Point3<T>, k: usize, accept: impl Fn(usize) -> bool) -> Vec<(u128, usize)> {
+        let mut query = Query { target, k, accept, best: BinaryHeap::with_capacity(k + 1) };
+        if k > 0 {
+            self.search(0, self.order.len(), 0, &mut query);
+        }
+        query.best.into_sorted_vec()
+    }
+
+    fn search<F: Fn(usize) -> bool>(&self, lo: usize, hi: usize, depth: usize, query: &mut Query<'_, T, F>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        if (query.accept)(index) {
+            query.best.push((query.target.dist_sq(point), index));
+            if query.best.len() > query.k {
+                query.best.pop();
+            }
+        }
+
+        let (near, far) = match axis(query.target, depth) < axis(point, depth) {
+            true => ((lo, mid), (mid + 1, hi)),
+            false => ((mid + 1, hi), (lo, mid)),
+        };
+        self.search(near.0, near.1, depth + 1, query);
+
+        // Points across the splitting plane are at least this far away
+        let plane = axis(query.target, depth).abs_diff_wide(axis(point, depth)).pow(2);
+        if query.best.len() < query.k || query.best.peek().is_some_and(|&(worst, _)| plane <= worst) {
+            self.search(far.0, far.1, depth + 1, query);
+        }
+    }
+}
+
+/// State of one nearest-neighbor search: the `k` best candidates so far, worst on top.
+struct Query<'a, T, F> {
+    target: &'a Point3<T>,
+    k: usize,
+    accept: F,
+    best: BinaryHeap<(u128, usize)>,
+}
+
+/// Neighbors of one point with a larger index, fetched from the tree in growing batches.
+#[derive(Debug, Clone)]
+struct Neighbors {
+    fetched: Vec<(u128, usize)>,
+    next: usize,
+    exhausted: bool,
+}
+
+/// Every pair of points, streamed in increasing [`Edge`] order without
+/// materializing the full list of pairs. See [`nearest_pairs`].
+#[derive(Debug, Clone)]
+pub struct NearestPairs<T> {
+    tree: KdTree<T>,
+    neighbors: Vec<Neighbors>,
+    heads: BinaryHeap<Reverse<Edge>>,
+}
+
+/// Streams the pairs of `points` from closest to furthest apart.
+pub fn nearest_pairs<T: Coord>(points: &[Point3<T>]) -> NearestPairs<T> {
+    let empty = Neighbors { fetched: Vec::new(), next: 0, exhausted: false };
+    let mut pairs = NearestPairs {
+        tree: KdTree::new(points),
+        neighbors: vec![empty; points.len()],
+        heads: BinaryHeap::new(),
+    };
+    for i in 0..points.len() {
+        pairs.push_head(i);
+    }
+    pairs
+}
+
+impl<T: Coord> NearestPairs<T> {
+
+    const FIRST_BATCH: usize = 4;
+
+    /// Queues the next unvisited neighbor of point `i`, fetching more if needed.
+    fn push_head(&mut self, i: usize) {
+        let neighbors = &mut self.neighbors[i];
+        if neighbors.next == neighbors.fetched.len() && !neighbors.exhausted {
+            let k = (neighbors.fetched.len() * 2).max(Self::FIRST_BATCH);
+            let fetched = self.tree.nearest_where(&self.tree.points[i], k, |j| j > i);
+            neighbors.exhausted = fetched.len() < k;
+            neighbors.fetched = fetched;
+        }
+
+        if let Some(&(dist_sq, j)) = neighbors.fetched.get(neighbors.next) {
+            neighbors.next += 1;
+            self.heads.push(Reverse(Edge { dist_sq, ends: (i, j) }));
+        }
+    }
+}
+
+impl<T: Coord> Iterator for NearestPairs<T> {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Edge> {
+        let Reverse(edge) = self.heads.pop()?;
+        self.push_head(edge.ends.0);
+        Some(edge)
+    }
+}
+
+/// The Euclidean minimum spanning tree of `points`, as its edges in increasing
+/// order. Runs Prim's algorithm over the implicit complete graph, so it needs
+/// quadratic time but only linear memory.
+pub fn euclidean_mst<T: Coord>(points: &[Point3<T>]) -> Vec<Edge> {
+    let mut edges = Vec::with_capacity(points.len().saturating_sub(1));
+    if points.is_empty() {
+        return edges;
+    }
+
+    let mut in_tree = vec![false; points.len()];
+    let mut best: Vec<Option<Edge>> = vec![None; points.len()];
+    let mut current = 0;
+    in_tree[current] = true;
+
+    for _ in 1..points.len() {
+        let mut next: Option<usize> = None;
+        for v in 0..points.len() {
+            if in_tree[v] {
+                continue;
+            }
+            let edge = Edge::new((current.min(v), current.max(v)), &points[current], &points[v]);
+            if best[v].is_none_or(|b| edge < b) {
+                best[v] = Some(edge);
+            }
+            if next.is_none_or(|u| best[v] < best[u]) {
+                next = Some(v);
+            }
+        }
+
+        let v = next.unwrap();
+        in_tree[v] = true;
+        edges.extend(best[v]);
+        current = v;
+    }
+
+    edges.sort_unstable();
+    edges
+}
+
+/// One step of Kruskal's algorithm: `edge` joined components of `sizes.0` and
+/// `sizes.1` points, leaving `components` in total. `links`, when known, counts
+/// the edges made so far, this one included and whether or not they merged
+/// anything, so it is the position of `edge`, from 1, in the order the edges
+/// were connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merge {
+    pub edge: Edge,
+    pub links: Option<usize>,
+    pub sizes: (usize, usize),
+    pub components: usize,
+}
+
+/// Runs Kruskal's algorithm on `len` points, connecting `edges` in the order given
+/// (which should be increasing), and returns every merge until one component is left.
+pub fn kruskal(len: usize, edges: impl IntoIterator<Item = Edge>) -> Vec<Merge> {
+    kruskal_ranked(len, edges.into_iter().enumerate().map(|(i, edge)| (Some(i + 1), edge)))
+}
+
+/// As [`kruskal`], with each edge given alongside its position among all edges, if known.
+fn kruskal_ranked(len: usize, edges: impl IntoIterator<Item = (Option<usize>, Edge)>) -> Vec<Merge> {
+    let mut set = DisjointSet::new(len);
+    let mut merges = Vec::with_capacity(len.saturating_sub(1));
+
+    for (links, edge) in edges {
+        if set.components() <= 1 {
+            break;
+        }
+        let (a, b) = edge.ends;
+        if set.same(a, b) {
+            continue;
+        }
+        let sizes = (set.size(a), set.size(b));
+        set.union(a, b);
+        merges.push(Merge { edge, links, sizes, components: set.components() });
+    }
+    merges
+}
+
+/// The whole sequence of merges that connecting every pair of `points`, closest
+/// first, would make, computed from the minimum spanning tree. The merges' `links`
+/// are left unknown, see [`rank_merges`].
+pub fn merge_sequence<T: Coord>(points: &[Point3<T>]) -> Vec<Merge> {
+    kruskal_ranked(points.len(), euclidean_mst(points).into_iter().map(|edge| (None, edge)))
+}
+
+/// Fills in the `links` of `merges`, as returned by [`merge_sequence`], by counting
+/// the pairs of `points` that order before each merge's edge. Takes O(n² log n) time.
+pub fn rank_merges<T: Coord>(points: &[Point3<T>], merges: &mut [Merge]) {
+    // Pairs falling between consecutive merge edges, so ranks follow by prefix sums
+    let mut between = vec![0; merges.len() + 1];
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let edge = Edge::new((i, j), &points[i], &points[j]);
+            between[merges.partition_point(|merge| merge.edge < edge)] += 1;
+        }
+    }
+
+    let mut rank = 0;
+    for (merge, count) in merges.iter_mut().zip(between) {
+        rank += count;
+        merge.links = Some(rank);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn points() -> Vec<Point3<i32>> {
+        // A small deterministic scatter with plenty of repeated distances
+        (0..60).map(|i: i32| Point3::new((i * 37) % 11, (i * 17) % 7, (i * 5) % 13)).collect()
+    }
+
+    fn all_pairs(points: &[Point3<i32>]) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                edges.push(Edge::new((i, j), &points[i], &points[j]));
+            }
+        }
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn test_nearest() {
+        let points = points();
+        let tree = KdTree::new(&points);
+        let target = Point3::new(5, 3, 6);
+
+        let mut expected: Vec<(u128, usize)> = points.iter()
+            .enumerate()
+            .map(|(i, p)| (target.dist_sq(p), i))
+            .collect();
+        expected.sort_unstable();
+
+        for k in [0, 1, 7, 60, 100] {
+            assert_eq!(tree.nearest(&target, k), expected[..k.min(60)]);
+        }
+        assert!(KdTree::<i32>::new(&[]).nearest(&target, 3).is_empty());
+    }
+
+    #[test]
+    fn test_nearest_pairs() {
+        let points = points();
+        assert_eq!(nearest_pairs(&points).collect::<Vec<_>>(), all_pairs(&points));
+        assert_eq!(nearest_pairs(&points[..1]).count(), 0);
+    }
+
+    #[test]
+    fn test_euclidean_mst() {
+        let points = points();
+        let mst = euclidean_mst(&points);
+        assert_eq!(mst.len(), points.len() - 1);
+
+        // Kruskal over every pair keeps exactly the spanning tree edges
+        let mut set = DisjointSet::new(points.len());
+        let kruskal: Vec<Edge> = all_pairs(&points).into_iter()
+            .filter(|edge| set.union(edge.ends.0, edge.ends.1))
+            .collect();
+        assert_eq!(mst, kruskal);
+        assert!(euclidean_mst::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_merge_sequence() {
+        let points = points();
+        let mut merges = merge_sequence(&points);
+        assert_eq!(merges.len(), points.len() - 1);
+        assert_eq!(merges.last().unwrap().components, 1);
+        assert!(merges.iter().all(|merge| merge.links.is_none()));
+
+        for (i, merge) in merges.iter().enumerate() {
+            assert_eq!(merge.components, points.len() - i - 1);
+        }
+
+        // Links count every pair connected, including those within a component
+        rank_merges(&points, &mut merges);
+        assert_eq!(merges, kruskal(points.len(), nearest_pairs(&points)));
+        let pairs = all_pairs(&points);
+        for merge in &merges {
+            assert_eq!(pairs[merge.links.unwrap() - 1], merge.edge);
+        }
+        assert_eq!(merges[0].links, Some(1));
+        let last = merges.last().unwrap();
+        assert_eq!(last.sizes.0 + last.sizes.1, points.len());
+        assert!(kruskal(1, nearest_pairs(&points)).is_empty());
+    }
+}
@@ -0,0 +1,356 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A closed [`Span`]. `path` joins the names of every span open around it with `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanRecord {
+    pub path: String,
+    pub depth: usize,
+    pub elapsed: Duration,
+}
+
+pub struct RunTimer {
+    next_id: Cell<usize>,
+    open: RefCell<Vec<(usize, String)>>,
+    paths: RefCell<Vec<(String, usize)>>,
+    spans: RefCell<Vec<SpanRecord>>,
+    benches: RefCell<Vec<PhaseStats>>,
+}
+
+impl Default for RunTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunTimer {
+
+    pub fn new() -> Self {
+        Self {
+            next_id: Cell::new(0),
+            open: RefCell::new(Vec::new()),
+            paths: RefCell::new(Vec::new()),
+            spans: RefCell::new(Vec::new()),
+            benches: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Opens a span named `name`, nested inside whichever spans are still open.
+    /// It is recorded when the returned guard is closed or dropped.
+    pub fn span(&self, name: impl AsRef<str>) -> Span<'_> {
+        let (path, depth) = self.nested_path(name.as_ref());
+
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.open.borrow_mut().push((id, path.clone()));
+
+        Span { timer: self, id, path, depth, start: Instant::now(), closed: false }
+    }
+
+    /// The path and depth of `name` nested inside the open spans, remembered
+    /// so that [`RunTimer::summary`] lists it in order.
+    fn nested_path(&self, name: &str) -> (String, usize) {
+        let open = self.open.borrow();
+        let path = match open.last() {
+            Some((_, parent)) => format!("{}/{}", parent, name),
+            None => name.to_owned(),
+        };
+        let depth = open.len();
+
+        let mut paths = self.paths.borrow_mut();
+        if !paths.iter().any(|(p, _)| *p == path) {
+            paths.push((path.clone(), depth));
+        }
+        (path, depth)
+    }
+
+    /// Runs `f` repeatedly as a phase named `name`, nested like a span: first
+    /// `config.warmup` untimed iterations, then either `config.iterations` timed
+    /// ones or as many as fit in `config.budget`, but always at least one.
+    /// Records and returns the statistics over the timed iterations as a
+    /// single phase rather than one span per iteration.
+    pub fn bench<T>(&self, name: impl AsRef<str>, config: &BenchConfig, mut f: impl FnMut() -> T) -> PhaseStats {
+        for _ in 0..config.warmup {
+            black_box(f());
+        }
+
+        let (path, depth) = self.nested_path(name.as_ref());
+        let started = Instant::now();
+        let mut runs = Vec::new();
+        loop {
+            let done = match config.iterations {
+                Some(iterations) => runs.len() >= iterations.max(1),
+                None => !runs.is_empty() && started.elapsed() >= config.budget,
+            };
+            if done {
+                break;
+            }
+
+            let start = Instant::now();
+            black_box(f());
+            runs.push(start.elapsed());
+        }
+
+        let stats = PhaseStats::from_runs(path, depth, &runs)
+            .expect("a benchmark always runs at least once");
+        self.benches.borrow_mut().push(stats.clone());
+        stats
+    }
+
+    /// Every closed span, in the order they were closed.
+    pub fn spans(&self) -> Vec<SpanRecord> {
+        self.spans.borrow().clone()
+    }
+
+    /// Aggregates closed spans by path, alongside every benchmarked phase,
+    /// in the order each path was first opened.
+    pub fn summary(&self) -> Summary {
+        let spans = self.spans.borrow();
+        let benches = self.benches.borrow();
+        let phases = self.paths.borrow().iter()
+            .flat_map(|(path, depth)| {
+                let runs: Vec<Duration> = spans.iter()
+                    .filter(|record| record.path == *path)
+                    .map(|record| record.elapsed)
+                    .collect();
+                PhaseStats::from_runs(path.clone(), *depth, &runs).into_iter()
+                    .chain(benches.iter().filter(|stats| stats.path == *path).cloned())
+            })
+            .collect();
+
+        Summary { phases }
+    }
+}
+
+/// Guard for an open span of a [`RunTimer`], recorded when closed or dropped.
+pub struct Span<'a> {
+    timer: &'a RunTimer,
+    id: usize,
+    path: String,
+    depth: usize,
+    start: Instant,
+    closed: bool,
+}
+
+impl Span<'_> {
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Closes the span now, returning how long it was open.
+    pub fn close(mut self) -> Duration {
+        self.record()
+    }
+
+    fn record(&mut self) -> Duration {
+        let elapsed = self.start.elapsed();
+        self.closed = true;
+        self.timer.open.borrow_mut().retain(|(id, _)| *id != self.id);
+        self.timer.spans.borrow_mut().push(SpanRecord {
+            path: self.path.clone(),
+            depth: self.depth,
+            elapsed,
+        });
+        elapsed
+    }
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        if !self.closed {
+            self.record();
+        }
+    }
+}
+
+/// How many times [`RunTimer::bench`] runs its closure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed iterations run first to warm caches and branch predictors.
+    pub warmup: usize,
+    /// Exact number of timed iterations, overriding `budget` when set.
+    pub iterations: Option<usize>,
+    /// Time to keep running timed iterations for when `iterations` is unset.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Statistics over every run of one span path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseStats {
+    pub path: String,
+    pub depth: usize,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    pub total: Duration,
+}
+
+impl PhaseStats {
+
+    pub fn from_runs(path: String, depth: usize, runs: &[Duration]) -> Option<PhaseStats> {
+        if runs.is_empty() {
+            return None;
+        }
+
+        let mut sorted = runs.to_vec();
+        sorted.sort_unstable();
+
+        let total: Duration = sorted.iter().sum();
+        let mean = total / u32::try_from(sorted.len()).ok()?;
+        let variance = sorted.iter()
+            .map(|run| (run.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / sorted.len() as f64;
+        let p95_index = (sorted.len() * 95).div_ceil(100).saturating_sub(1);
+
+        Some(PhaseStats {
+            path,
+            depth,
+            runs: sorted.len(),
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            mean,
+            p95: sorted[p95_index],
+            max: sorted[sorted.len() - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            total,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+}
+
+/// Table of [`PhaseStats`], one row per span path, indented by nesting depth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub phases: Vec<PhaseStats>,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.phases.iter()
+            .map(|p| p.depth * 2 + p.name().len())
+            .chain([5])
+            .max()
+            .unwrap();
+
+        writeln!(f, "{:<width$} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            "phase", "runs", "min", "median", "mean", "p95", "max", "stddev")?;
+        for phase in &self.phases {
+            let name = format!("{}{}", "  ".repeat(phase.depth), phase.name());
+            writeln!(f, "{:<width$} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                name,
+                phase.runs,
+                format!("{:?}", phase.min),
+                format!("{:?}", phase.median),
+                format!("{:?}", phase.mean),
+                format!("{:?}", phase.p95),
+                format!("{:?}", phase.max),
+                format!("{:?}", phase.stddev))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_nested_spans() {
+        let timer = RunTimer::new();
+        {
+            let _day = timer.span("day5");
+            let parse = timer.span("parse");
+            assert_eq!(parse.path(), "day5/parse");
+            parse.close();
+            let _part1 = timer.span("part1");
+        }
+        let _after = timer.span("other").close();
+
+        let paths: Vec<(String, usize)> = timer.spans().into_iter()
+            .map(|record| (record.path, record.depth))
+            .collect();
+        assert_eq!(paths, vec![
+            ("day5/parse".to_owned(), 1),
+            ("day5/part1".to_owned(), 1),
+            ("day5".to_owned(), 0),
+            ("other".to_owned(), 0),
+        ]);
+    }
+
+    #[test]
+    fn test_summary() {
+        let timer = RunTimer::new();
+        for _ in 0..3 {
+            let _run = timer.span("run");
+            timer.span("parse").close();
+        }
+
+        let summary = timer.summary();
+        let rows: Vec<(&str, usize, usize)> = summary.phases.iter()
+            .map(|p| (p.path.as_str(), p.depth, p.runs))
+            .collect();
+        assert_eq!(rows, vec![("run", 0, 3), ("run/parse", 1, 3)]);
+        assert!(summary.phases.iter().all(|p| p.min <= p.mean && p.mean <= p.max));
+        assert!(summary.to_string().contains("\n  parse "));
+    }
+
+    #[test]
+    fn test_stats() {
+        let runs: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = PhaseStats::from_runs("run".to_owned(), 0, &runs).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.max, Duration::from_millis(20));
+        assert_eq!(stats.mean, Duration::from_micros(10500));
+        assert_eq!(stats.stddev.as_micros(), 5766);
+        assert_eq!(PhaseStats::from_runs("run".to_owned(), 0, &[]), None);
+    }
+
+    #[test]
+    fn test_bench_iterations() {
+        let timer = RunTimer::new();
+        let config = BenchConfig { warmup: 2, iterations: Some(5), ..BenchConfig::default() };
+        let mut calls = 0;
+        let _day = timer.span("day1");
+        let stats = timer.bench("part1", &config, || calls += 1);
+
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.path, "day1/part1");
+        assert!(timer.spans().is_empty());
+        assert_eq!(timer.summary().phases, vec![stats]);
+    }
+
+    #[test]
+    fn test_bench_budget() {
+        let timer = RunTimer::new();
+        let config = BenchConfig { warmup: 0, iterations: None, budget: Duration::ZERO };
+        let stats = timer.bench("part1", &config, || ());
+        assert_eq!(stats.runs, 1);
+
+        let config = BenchConfig { warmup: 0, iterations: Some(0), ..BenchConfig::default() };
+        assert_eq!(timer.bench("part2", &config, || ()).runs, 1);
+    }
+}
@@ -0,0 +1,39 @@
+/// A xorshift64 generator: a fixed, seeded sequence of pseudo-random numbers, so that
+/// randomized tests are reproducible without pulling in a crate.
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+
+    /// Starts the sequence at `seed`, which must not be zero.
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "a xorshift seed must not be zero");
+        Self { state: seed }
+    }
+}
+
+impl Iterator for XorShift64 {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        Some(self.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_sequence() {
+        let values: Vec<u64> = XorShift64::new(1).take(3).collect();
+        assert_eq!(values, [1082269761, 1152992998833853505, 11177516664432764457]);
+        assert!(XorShift64::new(0x9e3779b97f4a7c15).take(1000).all(|value| value != 0));
+    }
+}
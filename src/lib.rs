@@ -1,7 +1,34 @@
 pub mod aocutils {
 
+    pub mod bignum;
+    pub mod digits;
+    pub mod dsu;
+    pub mod geom;
+    pub mod grid;
+    pub mod intervals;
+    pub mod repetition;
+    pub mod spatial;
+    // Only for the randomized tests, which for the days live outside this crate
+    #[doc(hidden)]
+    pub mod xorshift;
+
+    mod answers;
+    mod error;
+    mod examples;
+    mod input;
+    mod output;
+    mod solution;
+    mod timer;
+
+    pub use answers::{Answers, Verdict, ANSWERS_FILE};
+    pub use error::{parse_at, ParseError};
+    pub use examples::{check_examples, examples_dir};
+    pub use input::{InputSource, INPUT_DIR_VAR};
+    pub use output::{json_string, Format};
+    pub use solution::{bench, solve, Answer, Part, Report, Runner, Settings, Solution};
+    pub use timer::{BenchConfig, PhaseStats, RunTimer, Span, SpanRecord, Summary};
+
     use std::iter::Rev;
-    use std::time::Instant;
     use either::Either;
 
     pub trait CondRev: Iterator + Sized {
@@ -26,39 +53,4 @@ pub mod aocutils {
             .count();
         matching == a.len() && matching == b.len()
     }
-
-    pub struct RunTimer {
-        start: Instant,
-        times: Vec<Instant>
-    }
-
-    impl Default for RunTimer {
-        fn default() -> Self {
-            Self::new()
-        }
-    }
-
-    impl RunTimer {
-
-        pub fn new() -> Self {
-            Self {
-                start: Instant::now(),
-                times: Vec::new()
-            }
-        }
-
-        pub fn mark(&mut self) {
-            self.times.push(Instant::now());
-        }
-
-        pub fn finish(&mut self) {
-            self.mark();
-            println!();
-            let mut prev_instant = self.start;
-            for (i, instant) in self.times.iter().enumerate() {
-                println!("Elapsed Time (Part {}): {:?}", i + 1, instant.duration_since(prev_instant));
-                prev_instant = *instant;
-            }
-        }
-    }
 }